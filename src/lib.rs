@@ -2,6 +2,579 @@
 
 pub use ::include_sql::{include_sql, index_of};
 
+// Generated methods and constants are named after statements, like `get_books_with` or `GET_BOOKS_SQL`.
+// `macro_rules` cannot build identifiers, so only the macros that emit such names use `paste`.
+#[doc(hidden)]
+pub use ::paste;
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use ::serde;
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub mod params;
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub mod rows;
+
+#[cfg(feature = "render")]
+#[doc(hidden)]
+pub mod render;
+
+/**
+An SQL identifier - a column or a table name - that can be spliced into the statement text.
+
+Parameters that are declared as `ident<T>` are not bound. Instead `T` provides the identifier that
+replaces the parameter placeholder in the SQL. Use [`sql_ident`] to declare `T` as an enum of the allowed
+identifiers.
+*/
+pub trait SqlIdent {
+    /// All identifiers that values of this type represent. `check_all` prepares statements with each of them.
+    const IDENTS: &'static [&'static str];
+
+    /// Returns the identifier that will be spliced into SQL.
+    fn as_sql(&self) -> &'static str;
+}
+
+/**
+Declares an enum of SQL identifiers that can be used as values of `ident<T>` parameters.
+
+```rust
+include_sqlite_sql::sql_ident!{
+    /// Columns by which the books can be sorted
+    pub enum BookOrder {
+        Title  = book_title,
+        Author = book_author,
+        Loaned = "loaned on",
+    }
+}
+```
+
+Each variant is mapped to a single identifier, thus the set of SQL fragments that might be spliced
+into the statement is fixed at compile time. Identifiers are spliced double-quoted, so SQL keywords,
+like `order`, can be used as is. Names that are not valid Rust identifiers, or that are Rust keywords,
+like `type`, are given as string literals. They must not contain double quotes.
+*/
+#[macro_export]
+macro_rules! sql_ident {
+    ( $(#[$attr:meta])* $vis:vis enum $name:ident { $( $(#[$variant_attr:meta])* $variant:ident = $ident:tt ),+ $(,)? } ) => {
+        $(#[$attr])*
+        $vis enum $name {
+            $( $(#[$variant_attr])* $variant ),+
+        }
+        impl $crate::SqlIdent for $name {
+            const IDENTS: &'static [&'static str] = &[ $( $crate::quoted_ident!($ident) ),+ ];
+
+            fn as_sql(&self) -> &'static str {
+                match self {
+                    $( Self::$variant => $crate::quoted_ident!($ident) ),+
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! quoted_ident {
+    ($ident:ident) => { ::std::concat!('"', ::std::stringify!($ident), '"') };
+    ($ident:literal) => { ::std::concat!('"', $ident, '"') };
+}
+
+/**
+Error that is reported when an SQL value does not match any variant of an enum declared via [`sql_enum`].
+
+It is returned wrapped into `rusqlite::types::FromSqlError::Other`.
+*/
+#[derive(Debug)]
+pub struct UnknownVariant {
+    /// Name of the enum
+    pub enum_name: &'static str,
+    /// SQL value that was not recognized
+    pub value: String,
+}
+
+impl std::fmt::Display for UnknownVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` is not a valid {} value", self.value, self.enum_name)
+    }
+}
+
+impl std::error::Error for UnknownVariant {}
+
+/**
+Declares an enum that is stored in SQLite as either TEXT or INTEGER values.
+
+```rust
+include_sqlite_sql::sql_enum!{
+    /// Loan status
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum LoanStatus as text {
+        Loaned   = "loaned",
+        Returned = "returned",
+    }
+}
+
+include_sqlite_sql::sql_enum!{
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Priority as integer {
+        Low  = 1,
+        High = 2,
+    }
+}
+```
+
+The macro implements `rusqlite::ToSql` and `rusqlite::types::FromSql` for the enum. Thus it can be used
+as a declared type of statement parameters and retrieved from result columns via `rusqlite::Row::get`.
+Values that do not match any variant are reported as [`UnknownVariant`] errors.
+*/
+#[macro_export]
+macro_rules! sql_enum {
+    ( $(#[$attr:meta])* $vis:vis enum $name:ident as text { $( $(#[$variant_attr:meta])* $variant:ident = $value:literal ),+ $(,)? } ) => {
+        $(#[$attr])*
+        $vis enum $name {
+            $( $(#[$variant_attr])* $variant ),+
+        }
+        impl ::rusqlite::ToSql for $name {
+            fn to_sql(&self) -> ::rusqlite::Result<::rusqlite::types::ToSqlOutput<'_>> {
+                let value : &'static str = match self {
+                    $( Self::$variant => $value ),+
+                };
+                Ok(::rusqlite::types::ToSqlOutput::from(value))
+            }
+        }
+        impl ::rusqlite::types::FromSql for $name {
+            fn column_result(value: ::rusqlite::types::ValueRef<'_>) -> ::rusqlite::types::FromSqlResult<Self> {
+                match value.as_str()? {
+                    $( $value => Ok(Self::$variant), )+
+                    other => Err(::rusqlite::types::FromSqlError::Other(::std::boxed::Box::new($crate::UnknownVariant {
+                        enum_name: ::std::stringify!($name),
+                        value: ::std::string::String::from(other),
+                    }))),
+                }
+            }
+        }
+    };
+    ( $(#[$attr:meta])* $vis:vis enum $name:ident as integer { $( $(#[$variant_attr:meta])* $variant:ident = $value:literal ),+ $(,)? } ) => {
+        $(#[$attr])*
+        $vis enum $name {
+            $( $(#[$variant_attr])* $variant ),+
+        }
+        impl ::rusqlite::ToSql for $name {
+            fn to_sql(&self) -> ::rusqlite::Result<::rusqlite::types::ToSqlOutput<'_>> {
+                let value : i64 = match self {
+                    $( Self::$variant => $value ),+
+                };
+                Ok(::rusqlite::types::ToSqlOutput::from(value))
+            }
+        }
+        impl ::rusqlite::types::FromSql for $name {
+            fn column_result(value: ::rusqlite::types::ValueRef<'_>) -> ::rusqlite::types::FromSqlResult<Self> {
+                match value.as_i64()? {
+                    $( $value => Ok(Self::$variant), )+
+                    other => Err(::rusqlite::types::FromSqlError::Other(::std::boxed::Box::new($crate::UnknownVariant {
+                        enum_name: ::std::stringify!($name),
+                        value: other.to_string(),
+                    }))),
+                }
+            }
+        }
+    };
+}
+
+/**
+Error that is reported when a method argument does not satisfy the constraint that was declared for its parameter.
+
+It is returned wrapped into `rusqlite::Error::ToSqlConversionFailure`.
+*/
+#[derive(Debug)]
+pub struct InvalidArgument {
+    /// Name of the generated method
+    pub method: &'static str,
+    /// Name of the parameter
+    pub param: &'static str,
+    /// Description of the violated constraint
+    pub constraint: &'static str,
+}
+
+impl std::fmt::Display for InvalidArgument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "argument `{}` of `{}` {}", self.param, self.method, self.constraint)
+    }
+}
+
+impl std::error::Error for InvalidArgument {}
+
+/**
+Declares a wrapper of result rows with typed getters named after result columns.
+
+```rust
+include_sqlite_sql::sql_row!{
+    /// Rows returned by `get_loaned_books`
+    pub struct LoanedBook {
+        book_title: &str,
+        loaned_on: String,
+        book_id: i64,
+    }
+}
+```
+
+Getters of `&str` and `&[u8]` columns borrow the column data. Getters of other columns return values of
+the declared types that implement `rusqlite::types::FromSql`.
+
+The wrapper is passed to the callback that `map` adapts for the generated methods:
+
+```rust,ignore
+db.get_loaned_books("Sheldon Cooper", LoanedBook::map(|row| {
+    let title = row.book_title()?;
+    Ok(())
+}))?;
+```
+
+Column indices are resolved once, when the first row is processed. A missing column is reported
+as `rusqlite::Error::InvalidColumnName` at that point. Thus a misspelled column goes unnoticed while
+the statement returns no rows. `check` verifies the columns against a prepared statement instead:
+
+```rust,ignore
+LoanedBook::check(&db.prepare(<Connection as LibrarySql>::GET_LOANED_BOOKS_SQL)?)?;
+```
+*/
+#[macro_export]
+macro_rules! sql_row {
+    ( @one $col:ident ) => { 1 };
+    ( $(#[$attr:meta])* $vis:vis struct $name:ident { $($fields:tt)+ } ) => {
+        $crate::sql_row!{ @fields [$(#[$attr])*] [$vis] $name [] [] $($fields)+ }
+    };
+    ( @fields $attrs:tt [$vis:vis] $name:ident [$($col:ident)*] [$($getter:tt)*] $(#[$field_attr:meta])* $field:ident : &str $(, $($tail:tt)*)? ) => {
+        $crate::sql_row!{
+            @fields $attrs [$vis] $name [$($col)* $field]
+            [
+                $($getter)*
+                $(#[$field_attr])*
+                $vis fn $field(&self) -> ::rusqlite::Result<&'a str> {
+                    let idx = self.columns[0 $( + $crate::sql_row!(@one $col) )*];
+                    let value = self.row.get_ref(idx)?;
+                    value.as_str().map_err(|err| ::rusqlite::Error::FromSqlConversionFailure(idx, value.data_type(), ::std::boxed::Box::new(err)))
+                }
+            ]
+            $($($tail)*)?
+        }
+    };
+    ( @fields $attrs:tt [$vis:vis] $name:ident [$($col:ident)*] [$($getter:tt)*] $(#[$field_attr:meta])* $field:ident : &[u8] $(, $($tail:tt)*)? ) => {
+        $crate::sql_row!{
+            @fields $attrs [$vis] $name [$($col)* $field]
+            [
+                $($getter)*
+                $(#[$field_attr])*
+                $vis fn $field(&self) -> ::rusqlite::Result<&'a [u8]> {
+                    let idx = self.columns[0 $( + $crate::sql_row!(@one $col) )*];
+                    let value = self.row.get_ref(idx)?;
+                    value.as_blob().map_err(|err| ::rusqlite::Error::FromSqlConversionFailure(idx, value.data_type(), ::std::boxed::Box::new(err)))
+                }
+            ]
+            $($($tail)*)?
+        }
+    };
+    ( @fields $attrs:tt [$vis:vis] $name:ident [$($col:ident)*] [$($getter:tt)*] $(#[$field_attr:meta])* $field:ident : $ftype:ty $(, $($tail:tt)*)? ) => {
+        $crate::sql_row!{
+            @fields $attrs [$vis] $name [$($col)* $field]
+            [
+                $($getter)*
+                $(#[$field_attr])*
+                $vis fn $field(&self) -> ::rusqlite::Result<$ftype> {
+                    self.row.get(self.columns[0 $( + $crate::sql_row!(@one $col) )*])
+                }
+            ]
+            $($($tail)*)?
+        }
+    };
+    ( @fields [$($attr:tt)*] [$vis:vis] $name:ident [$($col:ident)+] [$($getter:tt)*] ) => {
+        $($attr)*
+        $vis struct $name<'a, 'stmt> {
+            row: &'a ::rusqlite::Row<'stmt>,
+            columns: &'a [usize],
+        }
+
+        impl<'a, 'stmt> $name<'a, 'stmt> {
+            $($getter)*
+
+            /// Checks that the statement returns every column of the row.
+            $vis fn check(stmt: &::rusqlite::Statement) -> ::rusqlite::Result<()> {
+                $( stmt.column_index(::std::stringify!($col))?; )+
+                Ok(())
+            }
+
+            /// Adapts a callback that processes wrapped rows for the generated methods.
+            $vis fn map<F, R>(mut row_cb: F) -> impl FnMut(&::rusqlite::Row) -> ::rusqlite::Result<R>
+            where F: FnMut($name) -> ::rusqlite::Result<R>
+            {
+                let mut columns = ::std::option::Option::None;
+                move |row: &::rusqlite::Row| {
+                    let columns = match columns {
+                        ::std::option::Option::Some(ref columns) => columns,
+                        ::std::option::Option::None => {
+                            let stmt = row.as_ref();
+                            columns.insert([ $( stmt.column_index(::std::stringify!($col))? ),+ ])
+                        }
+                    };
+                    row_cb($name { row, columns })
+                }
+            }
+        }
+    };
+}
+
+/**
+Conversion of a result row into a value.
+
+`Row` is `rusqlite::Row`. Methods that return converted rows are generated for `?` and `->` statements
+of the files that request `rows` methods via [`impl_sql_extra`].
+Use [`sql_from_row`] to implement this trait for a struct.
+*/
+pub trait FromRow<Row>: Sized {
+    /// Error that is reported when the row cannot be converted. It is expected to be `rusqlite::Error`.
+    type Error;
+
+    /// Converts the row.
+    fn from_row(row: &Row) -> Result<Self, Self::Error>;
+}
+
+/**
+Declares a struct that implements [`FromRow`] by retrieving each field from the result column of the same name.
+
+```rust
+include_sqlite_sql::sql_from_row!{
+    #[derive(Debug)]
+    pub struct Book {
+        pub book_id: i64,
+        #[rename = "book_title"]
+        pub title: String,
+        #[skip]
+        pub notes: Vec<String>,
+    }
+}
+```
+
+A field can be mapped to a differently named column via `#[rename = "column"]`. Fields marked as `#[skip]`
+are not retrieved. They are initialized with their `Default` values.
+*/
+#[macro_export]
+macro_rules! sql_from_row {
+    ( $(#[$attr:meta])* $vis:vis struct $name:ident { $($fields:tt)+ } ) => {
+        $crate::sql_from_row!{ @field row [$(#[$attr])*] [$vis] $name [] [] [] [] $($fields)+ }
+    };
+    ( @field $row:ident $attrs:tt $vis:tt $name:ident [$($decl:tt)*] [$($init:tt)*] [$($field_attr:tt)*] [$($column:tt)*] #[rename = $rename:literal] $($tail:tt)+ ) => {
+        $crate::sql_from_row!{ @field $row $attrs $vis $name [$($decl)*] [$($init)*] [$($field_attr)*] [$rename] $($tail)+ }
+    };
+    ( @field $row:ident $attrs:tt $vis:tt $name:ident [$($decl:tt)*] [$($init:tt)*] [$($field_attr:tt)*] [$($column:tt)*] #[skip] $($tail:tt)+ ) => {
+        $crate::sql_from_row!{ @field $row $attrs $vis $name [$($decl)*] [$($init)*] [$($field_attr)*] [skip] $($tail)+ }
+    };
+    ( @field $row:ident $attrs:tt $vis:tt $name:ident [$($decl:tt)*] [$($init:tt)*] [$($field_attr:tt)*] [$($column:tt)*] #[$other:meta] $($tail:tt)+ ) => {
+        $crate::sql_from_row!{ @field $row $attrs $vis $name [$($decl)*] [$($init)*] [$($field_attr)* #[$other]] [$($column)*] $($tail)+ }
+    };
+    ( @field $row:ident $attrs:tt $vis:tt $name:ident [$($decl:tt)*] [$($init:tt)*] [$($field_attr:tt)*] [skip] $field_vis:vis $field:ident : $ftype:ty $(, $($tail:tt)*)? ) => {
+        $crate::sql_from_row!{
+            @field $row $attrs $vis $name
+            [$($decl)* $($field_attr)* $field_vis $field : $ftype ,]
+            [$($init)* $field : ::std::default::Default::default() ,]
+            [] []
+            $($($tail)*)?
+        }
+    };
+    ( @field $row:ident $attrs:tt $vis:tt $name:ident [$($decl:tt)*] [$($init:tt)*] [$($field_attr:tt)*] [$column:literal] $field_vis:vis $field:ident : $ftype:ty $(, $($tail:tt)*)? ) => {
+        $crate::sql_from_row!{
+            @field $row $attrs $vis $name
+            [$($decl)* $($field_attr)* $field_vis $field : $ftype ,]
+            [$($init)* $field : $row.get($column)? ,]
+            [] []
+            $($($tail)*)?
+        }
+    };
+    ( @field $row:ident $attrs:tt $vis:tt $name:ident [$($decl:tt)*] [$($init:tt)*] [$($field_attr:tt)*] [] $field_vis:vis $field:ident : $ftype:ty $(, $($tail:tt)*)? ) => {
+        $crate::sql_from_row!{
+            @field $row $attrs $vis $name
+            [$($decl)* $($field_attr)* $field_vis $field : $ftype ,]
+            [$($init)* $field : $row.get(::std::stringify!($field))? ,]
+            [] []
+            $($($tail)*)?
+        }
+    };
+    ( @field $row:ident [$($attr:tt)*] [$vis:vis] $name:ident [$($decl:tt)*] [$($init:tt)*] [] [] ) => {
+        $($attr)*
+        $vis struct $name {
+            $($decl)*
+        }
+        impl<'stmt> $crate::FromRow<::rusqlite::Row<'stmt>> for $name {
+            type Error = ::rusqlite::Error;
+
+            fn from_row($row: &::rusqlite::Row<'stmt>) -> ::rusqlite::Result<Self> {
+                Ok(Self {
+                    $($init)*
+                })
+            }
+        }
+    };
+}
+
+/**
+Error that is reported when the number of result columns does not match the number of tuple elements.
+
+`_tuples` methods check the number of columns when the statement is prepared and return this error
+wrapped into `rusqlite::Error::FromSqlConversionFailure`. The index of that error is the index of the first
+column or tuple element that has no counterpart. Its type is `Null` as no value has been read yet.
+*/
+#[derive(Debug)]
+pub struct ColumnCountMismatch {
+    /// Number of tuple elements
+    pub expected: usize,
+    /// Number of result columns
+    pub actual: usize,
+}
+
+impl std::fmt::Display for ColumnCountMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "statement returns {} columns, but {} were expected", self.actual, self.expected)
+    }
+}
+
+impl std::error::Error for ColumnCountMismatch {}
+
+/// Conversion of result rows into tuples of column values that [`sql_tuple`] creates.
+pub struct TupleMap<F> {
+    /// Number of tuple elements
+    pub columns: usize,
+    /// Function that converts a row into a tuple
+    pub map: F,
+}
+
+/**
+Creates a conversion of result rows into tuples of column values for `_tuples` methods.
+
+```rust,ignore
+let quotes : Vec<(String, i64)> = db.get_quotes_tuples(sql_tuple!(String, i64))?;
+```
+
+Each tuple element type should implement `rusqlite::types::FromSql`.
+*/
+#[macro_export]
+macro_rules! sql_tuple {
+    ( $($ctype:ty),+ $(,)? ) => {
+        $crate::TupleMap {
+            columns: $crate::count_types!($($ctype),+),
+            map: $crate::sql_tuple!(@get row [] [0] $($ctype),+),
+        }
+    };
+    ( @get $row:ident [$($get:tt)*] [$($idx:tt)+] $ctype:ty $(, $tail:ty)* ) => {
+        $crate::sql_tuple!(@get $row [$($get)* $row.get::<_, $ctype>($($idx)+)? ,] [$($idx)+ + 1] $($tail),*)
+    };
+    ( @get $row:ident [$($get:tt)+] [$($idx:tt)+] ) => {
+        |$row: &::rusqlite::Row| -> ::rusqlite::Result<_> { Ok(( $($get)+ )) }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! count_types {
+    () => { 0 };
+    ($head:ty $(, $tail:ty)*) => { 1 + $crate::count_types!($($tail),*) };
+}
+
+/// Kind of the generated method as it is selected by the statement name suffix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryKind {
+    /// `?` - processes selected rows
+    Select,
+    /// `!` - executes a non-select statement
+    Execute,
+    /// `&` - executes a batch of statements
+    Batch,
+    /// `->` - executes a statement with a `RETURNING` clause
+    Returning,
+}
+
+/// Metadata of a statement parameter.
+#[derive(Debug, Clone, Copy)]
+pub struct ParamInfo {
+    /// Parameter name
+    pub name: &'static str,
+    /// Whether this is an IN-list parameter
+    pub list: bool,
+    /// Parameter type as it was declared in SQL or `_` if it was not declared
+    pub ptype: &'static str,
+    /// Whether the parameter was declared as `sensitive<T>` and its values should be masked
+    pub sensitive: bool,
+}
+
+/**
+Metadata of an included statement.
+
+`impl_sql` lists metadata of all statements of the included file in the `QUERIES` associated
+constant of the generated trait.
+*/
+#[derive(Debug, Clone, Copy)]
+pub struct QueryInfo {
+    /// Statement name
+    pub name: &'static str,
+    /// Kind of the generated method
+    pub kind: QueryKind,
+    /// Statement SQL with `:name` parameter placeholders as it was included
+    pub sql: &'static str,
+    /// Statement documentation including parameter descriptions
+    pub doc: &'static str,
+    /// Statement parameters in the order of the generated method parameters
+    pub params: &'static [ParamInfo],
+}
+
+/// A scalar statement parameter as it is seen by the dynamic SQL builder.
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub enum DynParam {
+    /// Position of the parameter argument in the list of bound arguments
+    Bind(usize),
+    /// Identifier that replaces the parameter placeholder
+    Ident(&'static str),
+}
+
+/// Returns the position of the first `?`, `@name` or `$name` placeholder in the statement text, if any.
+/// Quoted strings and identifiers, and comments are skipped.
+#[doc(hidden)]
+pub const fn find_unsupported_placeholder(sql: &str) -> Option<usize> {
+    let sql = sql.as_bytes();
+    let mut i = 0;
+    while i < sql.len() {
+        let c = sql[i];
+        if c == b'\'' || c == b'"' || c == b'`' || c == b'[' {
+            let end = if c == b'[' { b']' } else { c };
+            i += 1;
+            while i < sql.len() && sql[i] != end {
+                i += 1;
+            }
+        } else if c == b'-' && i + 1 < sql.len() && sql[i + 1] == b'-' {
+            while i < sql.len() && sql[i] != b'\n' {
+                i += 1;
+            }
+        } else if c == b'/' && i + 1 < sql.len() && sql[i + 1] == b'*' {
+            i += 2;
+            while i + 1 < sql.len() && !(sql[i] == b'*' && sql[i + 1] == b'/') {
+                i += 1;
+            }
+            i += 1;
+        } else if c == b'?' || c == b'@' {
+            return Some(i);
+        } else if c == b'$' {
+            // `$` is also allowed inside identifiers
+            let p = if i > 0 { sql[i - 1] } else { b' ' };
+            if !(p.is_ascii_alphanumeric() || p == b'_' || p == b'$' || p >= 0x80) {
+                return Some(i);
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
 /**
 Generates Rust code to use included SQL.
 
@@ -89,12 +662,161 @@ where F: FnOnce(&rusqlite::Row) -> rusqlite::Result<R>;
 */
 #[macro_export]
 macro_rules! impl_sql {
-    ( $sql_name:ident = $( { $kind:tt $name:ident ($($variant:tt $param:ident $ptype:tt)*) $doc:literal $s:tt $( $text:tt )+ } ),+ ) => {
+    ( $($t:tt)+ ) => {
+        $crate::impl_sql_extra!{ [] ; $($t)+ }
+    };
+}
+
+/**
+Generates the same code as [`impl_sql`] and the extra methods that are requested for the included statements.
+
+The macro is meant to be used by a module that declares its own `impl_sql` macro - the one that `include_sql` calls:
+
+```rust,ignore
+macro_rules! impl_sql {
+    ($($t:tt)+) => { include_sqlite_sql::impl_sql_extra!{ [rows deserialized] ; $($t)+ } };
+}
+
+include_sqlite_sql::include_sql!("/sql/library.sql");
+```
+
+Extra methods are named after the statement with a suffix that also names the request:
+- `with` - methods that bind parameters from a serializable value (requires the `serde` feature),
+- `rows` - `_rows` and `_row` methods that return converted rows,
+- `tuples` - `_tuples` methods that return rows as tuples of column values,
+- `deserialized` - methods that return deserialized rows (requires the `serde` feature), and
+- `render` - `render_` methods that return statement SQL with arguments (requires the `render` feature).
+
+Extra methods are thus generated only for the files that request them, and the crate features do not change
+generated traits of other crates in the same build.
+*/
+#[macro_export]
+macro_rules! impl_sql_extra {
+    ( $extra:tt ; $sql_name:ident = $( { $kind:tt $name:ident ($($variant:tt $param:ident $ptype:tt)*) $doc:literal $s:tt $( $text:tt )+ } ),+ ) => {
         trait $sql_name {
+            /// Metadata of the included statements
+            const QUERIES: &'static [$crate::QueryInfo] = &[
+                $(
+                    $crate::QueryInfo {
+                        name: ::std::stringify!($name),
+                        kind: $crate::query_kind!($kind),
+                        sql: $crate::source_sql!($($text)+),
+                        doc: $doc,
+                        params: &[
+                            $( $crate::ParamInfo { name: ::std::stringify!($param), list: $crate::is_list!($variant), ptype: $crate::param_type!($ptype), sensitive: $crate::is_sensitive!($ptype) } ),*
+                        ],
+                    }
+                ),+
+            ];
+            $( $crate::decl_sql_const!{ $name ($($param $variant $ptype)*) $($text)+ } )+
             $( $crate::decl_method!{ $kind $name $doc () () $($param $variant $ptype)* } )+
+            $( $crate::decl_extra_methods!{ $extra $sql_name $kind $name $doc ($($param $variant $ptype)*) } )+
+            /// Prepares every included statement (except batches) and returns errors of those that failed
+            fn check_all(&self) -> ::std::result::Result<(), ::std::vec::Vec<(&'static str, ::rusqlite::Error)>>;
         }
         impl $sql_name for ::rusqlite::Connection {
-            $( $crate::impl_method!{ $kind $name () () ($($param $variant $ptype)*) => ($($variant $param)*) $($text)+ } )+
+            $( $crate::impl_method!{ $kind $name () () {} ($($param $variant $ptype)*) => () $($text)+ } )+
+            $( $crate::impl_extra_methods!{ $extra $sql_name $kind $name ($($param $variant $ptype)*) $($text)+ } )+
+            fn check_all(&self) -> ::std::result::Result<(), ::std::vec::Vec<(&'static str, ::rusqlite::Error)>> {
+                let mut errors = ::std::vec::Vec::new();
+                $( $crate::check_stmt!{ self errors $kind $name ($($param $variant $ptype)*) $($text)+ } )+
+                if errors.is_empty() { Ok(()) } else { Err(errors) }
+            }
+        }
+        $( $crate::check_placeholders!{ $name $($text)+ } )+
+    };
+}
+
+/**
+Generates the same code as [`impl_sql`] and a test for each included statement.
+
+Each generated test opens an in-memory database, creates the schema from the specified SQL file, and then
+executes the statement with default arguments. The macro is meant to be used by a test module in place of
+`impl_sql`:
+
+```rust,ignore
+macro_rules! impl_sql {
+    ($($t:tt)+) => { include_sqlite_sql::impl_sql_tests!{ "/tests/schema.sql" ; $($t)+ } };
+}
+
+include_sqlite_sql::include_sql!("/tests/queries.sql");
+```
+
+The schema file path is relative to the project root. The file should contain plain SQL that can be executed as a batch.
+Extra methods, if any, are requested after the schema file path as they are for [`impl_sql_extra`]:
+`impl_sql_tests!{ "/tests/schema.sql" [rows] ; $($t)+ }`.
+*/
+#[macro_export]
+macro_rules! impl_sql_tests {
+    ( $schema:literal ; $($t:tt)+ ) => {
+        $crate::impl_sql_tests!{ $schema [] ; $($t)+ }
+    };
+    ( $schema:literal $extra:tt ; $sql_name:ident = $( { $kind:tt $name:ident ($($variant:tt $param:ident $ptype:tt)*) $doc:literal $s:tt $( $text:tt )+ } ),+ ) => {
+        $crate::impl_sql_extra!{ $extra ; $sql_name = $( { $kind $name ($($variant $param $ptype)*) $doc $s $($text)+ } ),+ }
+        $(
+            #[test]
+            fn $name() -> ::rusqlite::Result<()> {
+                let db = ::rusqlite::Connection::open_in_memory()?;
+                db.execute_batch(::std::include_str!(::std::concat!(::std::env!("CARGO_MANIFEST_DIR"), $schema)))?;
+                $crate::run_stmt!{ db $sql_name $kind $name [] ($($param $variant $ptype)*) }
+                Ok(())
+            }
+        )+
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! run_stmt {
+    ( $db:ident $sql_name:ident $kind:tt $name:ident [$($arg:expr),*] ($param:ident : _ $($tail:tt)*) ) => {
+        $crate::run_stmt!{ $db $sql_name $kind $name [$($arg ,)* ::rusqlite::types::Null] ($($tail)*) }
+    };
+    ( $db:ident $sql_name:ident $kind:tt $name:ident [$($arg:expr),*] ($param:ident : (default < $ptype:ty , $value:literal >) $($tail:tt)*) ) => {
+        $crate::run_stmt!{ $db $sql_name $kind $name [$($arg ,)* ::std::option::Option::None] ($($tail)*) }
+    };
+    ( $db:ident $sql_name:ident $kind:tt $name:ident [$($arg:expr),*] ($param:ident : (as_ref < $ptype:ty >) $($tail:tt)*) ) => {
+        $crate::run_stmt!{ $db $sql_name $kind $name [$($arg ,)* <&$ptype as ::std::default::Default>::default()] ($($tail)*) }
+    };
+    ( $db:ident $sql_name:ident $kind:tt $name:ident [$($arg:expr),*] ($param:ident : (range < $ptype:ty , $min:literal , $max:literal >) $($tail:tt)*) ) => {
+        $crate::run_stmt!{ $db $sql_name $kind $name [$($arg ,)* $min] ($($tail)*) }
+    };
+    ( $db:ident $sql_name:ident $kind:tt $name:ident [$($arg:expr),*] ($param:ident : (len < $min:literal , $max:literal >) $($tail:tt)*) ) => {
+        $crate::run_stmt!{ $db $sql_name $kind $name [$($arg ,)* "x".repeat($min).as_str()] ($($tail)*) }
+    };
+    ( $db:ident $sql_name:ident $kind:tt $name:ident [$($arg:expr),*] ($param:ident : (ident < $ptype:ty >) $($tail:tt)*) ) => {
+        $crate::run_stmt!{ $db $sql_name $kind $name [$($arg ,)* <$ptype as ::std::default::Default>::default()] ($($tail)*) }
+    };
+    ( $db:ident $sql_name:ident $kind:tt $name:ident [$($arg:expr),*] ($param:ident : (into < $ptype:ty >) $($tail:tt)*) ) => {
+        $crate::run_stmt!{ $db $sql_name $kind $name [$($arg ,)* <$ptype as ::std::default::Default>::default()] ($($tail)*) }
+    };
+    ( $db:ident $sql_name:ident $kind:tt $name:ident [$($arg:expr),*] ($param:ident : (sensitive < $ptype:ty >) $($tail:tt)*) ) => {
+        $crate::run_stmt!{ $db $sql_name $kind $name [$($arg ,)* <$ptype as ::std::default::Default>::default()] ($($tail)*) }
+    };
+    ( $db:ident $sql_name:ident $kind:tt $name:ident [$($arg:expr),*] ($param:ident : ($ptype:ty) $($tail:tt)*) ) => {
+        $crate::run_stmt!{ $db $sql_name $kind $name [$($arg ,)* <$ptype as ::std::default::Default>::default()] ($($tail)*) }
+    };
+    ( $db:ident $sql_name:ident $kind:tt $name:ident [$($arg:expr),*] ($param:ident # [$gtype:ident] $($tail:tt)*) ) => {
+        $crate::run_stmt!{ $db $sql_name $kind $name [$($arg ,)* &[::rusqlite::types::Null]] ($($tail)*) }
+    };
+    ( $db:ident $sql_name:ident $kind:tt $name:ident [$($arg:expr),*] ($param:ident # (sensitive < $ptype:ty >) $($tail:tt)*) ) => {
+        $crate::run_stmt!{ $db $sql_name $kind $name [$($arg ,)* &[<$ptype as ::std::default::Default>::default()]] ($($tail)*) }
+    };
+    ( $db:ident $sql_name:ident $kind:tt $name:ident [$($arg:expr),*] ($param:ident # ($ptype:ty) $($tail:tt)*) ) => {
+        $crate::run_stmt!{ $db $sql_name $kind $name [$($arg ,)* &[<$ptype as ::std::default::Default>::default()]] ($($tail)*) }
+    };
+    ( $db:ident $sql_name:ident ? $name:ident [$($arg:expr),*] () ) => {
+        $sql_name::$name(&$db, $($arg ,)* |_| Ok(()))?;
+    };
+    ( $db:ident $sql_name:ident ! $name:ident [$($arg:expr),*] () ) => {
+        $sql_name::$name(&$db $(, $arg)*)?;
+    };
+    ( $db:ident $sql_name:ident & $name:ident [$($arg:expr),*] () ) => {
+        $sql_name::$name(&$db $(, $arg)*)?;
+    };
+    ( $db:ident $sql_name:ident -> $name:ident [$($arg:expr),*] () ) => {
+        match $sql_name::$name(&$db, $($arg ,)* |_| Ok(())) {
+            Ok(()) | Err(::rusqlite::Error::QueryReturnedNoRows) => {},
+            Err(err) => return Err(err),
         }
     };
 }
@@ -115,6 +837,40 @@ macro_rules! decl_method {
         #[doc=$doc]
         fn $name<$($gen_type : ::rusqlite::ToSql),*>(&self $($fn_params)*) -> ::rusqlite::Result<()>;
     };
+    ( (rows $sql_name:ident $base:ident) $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) ) => {
+        #[doc=$doc]
+        fn $name<$($gen_type : ::rusqlite::ToSql ,)* T>(&self $($fn_params)*) -> ::rusqlite::Result<::std::vec::Vec<T>>
+        where T: for<'stmt> $crate::FromRow<::rusqlite::Row<'stmt>, Error = ::rusqlite::Error>;
+    };
+    ( (tuples $sql_name:ident $base:ident) $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) ) => {
+        #[doc=$doc]
+        fn $name<$($gen_type : ::rusqlite::ToSql ,)* T, F>(&self $($fn_params)* , tuple: $crate::TupleMap<F>) -> ::rusqlite::Result<::std::vec::Vec<T>>
+        where F: FnMut(&::rusqlite::Row) -> ::rusqlite::Result<T>;
+    };
+    ( (row ? $sql_name:ident $base:ident) $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) ) => {
+        #[doc=$doc]
+        fn $name<$($gen_type : ::rusqlite::ToSql ,)* T>(&self $($fn_params)*) -> ::rusqlite::Result<::std::option::Option<T>>
+        where T: for<'stmt> $crate::FromRow<::rusqlite::Row<'stmt>, Error = ::rusqlite::Error>;
+    };
+    ( (row -> $sql_name:ident $base:ident) $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) ) => {
+        #[doc=$doc]
+        fn $name<$($gen_type : ::rusqlite::ToSql ,)* T>(&self $($fn_params)*) -> ::rusqlite::Result<T>
+        where T: for<'stmt> $crate::FromRow<::rusqlite::Row<'stmt>, Error = ::rusqlite::Error>;
+    };
+    ( (de ? $sql_name:ident $base:ident) $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) ) => {
+        #[doc=$doc]
+        fn $name<$($gen_type : ::rusqlite::ToSql ,)* T>(&self $($fn_params)*) -> ::rusqlite::Result<::std::vec::Vec<T>>
+        where T: $crate::serde::de::DeserializeOwned;
+    };
+    ( (de -> $sql_name:ident $base:ident) $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) ) => {
+        #[doc=$doc]
+        fn $name<$($gen_type : ::rusqlite::ToSql ,)* T>(&self $($fn_params)*) -> ::rusqlite::Result<T>
+        where T: $crate::serde::de::DeserializeOwned;
+    };
+    ( render $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) ) => {
+        #[doc=$doc]
+        fn $name<$($gen_type : ::rusqlite::ToSql),*>(&self $($fn_params)*) -> ::rusqlite::Result<::std::string::String>;
+    };
     ( -> $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) ) => {
         #[doc=$doc]
         fn $name<$($gen_type : ::rusqlite::ToSql ,)* F, R>(&self $($fn_params)* , row_cb: F) -> ::rusqlite::Result<R>
@@ -130,7 +886,7 @@ macro_rules! decl_method {
             $($tail)*
         }
     };
-    ( $kind:tt $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) $param:ident : ($ptype:ty) $($tail:tt)* ) => {
+    ( $kind:tt $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) $param:ident : (ident < $ptype:ty >) $($tail:tt)* ) => {
         $crate::decl_method!{
             $kind
             $name
@@ -140,32 +896,201 @@ macro_rules! decl_method {
             $($tail)*
         }
     };
-    ( $kind:tt $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) $param:ident # [$gtype:ident] $($tail:tt)* ) => {
+    ( $kind:tt $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) $param:ident : (default < $ptype:ty , $value:literal >) $($tail:tt)* ) => {
         $crate::decl_method!{
             $kind
             $name
             $doc
-            ($($gen_type)* $gtype)
-            ($($fn_params)* , $param : & [ $gtype ] )
+            ($($gen_type)*)
+            ($($fn_params)* , $param : ::std::option::Option<$ptype>)
             $($tail)*
         }
     };
-    ( $kind:tt $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) $param:ident # ($ptype:ty) $($tail:tt)* ) => {
+    ( $kind:tt $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) $param:ident : (into < $ptype:ty >) $($tail:tt)* ) => {
         $crate::decl_method!{
             $kind
             $name
             $doc
             ($($gen_type)*)
-            ($($fn_params)* , $param : & [ $ptype ] )
+            ($($fn_params)* , $param : impl ::std::convert::Into<$ptype>)
             $($tail)*
         }
     };
-}
-
-#[macro_export]
-#[doc(hidden)]
+    ( $kind:tt $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) $param:ident : (as_ref < $ptype:ty >) $($tail:tt)* ) => {
+        $crate::decl_method!{
+            $kind
+            $name
+            $doc
+            ($($gen_type)*)
+            ($($fn_params)* , $param : impl ::std::convert::AsRef<$ptype>)
+            $($tail)*
+        }
+    };
+    ( $kind:tt $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) $param:ident : (range < $ptype:ty , $min:literal , $max:literal >) $($tail:tt)* ) => {
+        $crate::decl_method!{
+            $kind
+            $name
+            $doc
+            ($($gen_type)*)
+            ($($fn_params)* , $param : $ptype)
+            $($tail)*
+        }
+    };
+    ( $kind:tt $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) $param:ident : (len < $min:literal , $max:literal >) $($tail:tt)* ) => {
+        $crate::decl_method!{
+            $kind
+            $name
+            $doc
+            ($($gen_type)*)
+            ($($fn_params)* , $param : &str)
+            $($tail)*
+        }
+    };
+    ( $kind:tt $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) $param:ident : (sensitive < $ptype:ty >) $($tail:tt)* ) => {
+        $crate::decl_method!{
+            $kind
+            $name
+            $doc
+            ($($gen_type)*)
+            ($($fn_params)* , $param : $ptype)
+            $($tail)*
+        }
+    };
+    ( $kind:tt $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) $param:ident : ($ptype:ty) $($tail:tt)* ) => {
+        $crate::decl_method!{
+            $kind
+            $name
+            $doc
+            ($($gen_type)*)
+            ($($fn_params)* , $param : $ptype)
+            $($tail)*
+        }
+    };
+    ( $kind:tt $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) $param:ident # [$gtype:ident] $($tail:tt)* ) => {
+        $crate::decl_method!{
+            $kind
+            $name
+            $doc
+            ($($gen_type)* $gtype)
+            ($($fn_params)* , $param : & [ $gtype ] )
+            $($tail)*
+        }
+    };
+    ( $kind:tt $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) $param:ident # (sensitive < $ptype:ty >) $($tail:tt)* ) => {
+        $crate::decl_method!{
+            $kind
+            $name
+            $doc
+            ($($gen_type)*)
+            ($($fn_params)* , $param : & [ $ptype ] )
+            $($tail)*
+        }
+    };
+    ( $kind:tt $name:ident $doc:literal ($($gen_type:ident)*) ($($fn_params:tt)*) $param:ident # ($ptype:ty) $($tail:tt)* ) => {
+        $crate::decl_method!{
+            $kind
+            $name
+            $doc
+            ($($gen_type)*)
+            ($($fn_params)* , $param : & [ $ptype ] )
+            $($tail)*
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
 macro_rules! impl_method {
-    ( ? $name:ident () () () => () $text:literal ) => {
+    ( (de ? $sql_name:ident $base:ident) $name:ident ($($gen_type:ident)*) ($(, $arg:ident : $arg_type:ty)*) {$($prologue:tt)*} () => ($($pv:tt $param:ident)*) $($text:tt)+ ) => {
+        fn $name<$($gen_type : ::rusqlite::ToSql ,)* T>(&self $(, $arg : $arg_type)*) -> ::rusqlite::Result<::std::vec::Vec<T>>
+        where T: $crate::serde::de::DeserializeOwned
+        {
+            let mut rows = ::std::vec::Vec::new();
+            <Self as $sql_name>::$base(self, $($arg ,)* |row| {
+                rows.push($crate::deserialize_row!(row)?);
+                Ok(())
+            })?;
+            Ok(rows)
+        }
+    };
+    ( (de -> $sql_name:ident $base:ident) $name:ident ($($gen_type:ident)*) ($(, $arg:ident : $arg_type:ty)*) {$($prologue:tt)*} () => ($($pv:tt $param:ident)*) $($text:tt)+ ) => {
+        fn $name<$($gen_type : ::rusqlite::ToSql ,)* T>(&self $(, $arg : $arg_type)*) -> ::rusqlite::Result<T>
+        where T: $crate::serde::de::DeserializeOwned
+        {
+            <Self as $sql_name>::$base(self, $($arg ,)* |row| $crate::deserialize_row!(row))
+        }
+    };
+    ( (rows $sql_name:ident $base:ident) $name:ident ($($gen_type:ident)*) ($(, $arg:ident : $arg_type:ty)*) {$($prologue:tt)*} () => ($($pv:tt $param:ident)*) $($text:tt)+ ) => {
+        fn $name<$($gen_type : ::rusqlite::ToSql ,)* T>(&self $(, $arg : $arg_type)*) -> ::rusqlite::Result<::std::vec::Vec<T>>
+        where T: for<'stmt> $crate::FromRow<::rusqlite::Row<'stmt>, Error = ::rusqlite::Error>
+        {
+            let mut rows = ::std::vec::Vec::new();
+            <Self as $sql_name>::$base(self, $($arg ,)* |row| {
+                rows.push(T::from_row(row)?);
+                Ok(())
+            })?;
+            Ok(rows)
+        }
+    };
+    ( (row ? $sql_name:ident $base:ident) $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} () => ($($pv:tt $param:ident)*) $($text:tt)+ ) => {
+        fn $name<$($gen_type : ::rusqlite::ToSql ,)* T>(&self $($fn_params)*) -> ::rusqlite::Result<::std::option::Option<T>>
+        where T: for<'stmt> $crate::FromRow<::rusqlite::Row<'stmt>, Error = ::rusqlite::Error>
+        {
+            $($prologue)*
+            $crate::prepare_stmt!(self stmt ($($pv $param)*) $($text)+);
+            let mut rows = stmt.raw_query();
+            match rows.next()? {
+                Some(row) => T::from_row(row).map(::std::option::Option::Some),
+                _ => Ok(::std::option::Option::None)
+            }
+        }
+    };
+    ( (tuples $sql_name:ident $base:ident) $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} () => ($($pv:tt $param:ident)*) $($text:tt)+ ) => {
+        fn $name<$($gen_type : ::rusqlite::ToSql ,)* T, F>(&self $($fn_params)* , mut tuple: $crate::TupleMap<F>) -> ::rusqlite::Result<::std::vec::Vec<T>>
+        where F: FnMut(&::rusqlite::Row) -> ::rusqlite::Result<T>
+        {
+            $($prologue)*
+            $crate::prepare_stmt!(self stmt ($($pv $param)*) $($text)+);
+            let columns = stmt.column_count();
+            if columns != tuple.columns {
+                return Err(::rusqlite::Error::FromSqlConversionFailure(
+                    ::std::cmp::min(columns, tuple.columns),
+                    ::rusqlite::types::Type::Null,
+                    ::std::boxed::Box::new($crate::ColumnCountMismatch { expected: tuple.columns, actual: columns }),
+                ));
+            }
+            let mut tuples = ::std::vec::Vec::new();
+            let mut rows = stmt.raw_query();
+            while let Some(row) = rows.next()? {
+                tuples.push((tuple.map)(row)?);
+            }
+            Ok(tuples)
+        }
+    };
+    ( (row -> $sql_name:ident $base:ident) $name:ident ($($gen_type:ident)*) ($(, $arg:ident : $arg_type:ty)*) {$($prologue:tt)*} () => ($($pv:tt $param:ident)*) $($text:tt)+ ) => {
+        fn $name<$($gen_type : ::rusqlite::ToSql ,)* T>(&self $(, $arg : $arg_type)*) -> ::rusqlite::Result<T>
+        where T: for<'stmt> $crate::FromRow<::rusqlite::Row<'stmt>, Error = ::rusqlite::Error>
+        {
+            <Self as $sql_name>::$base(self, $($arg ,)* |row| T::from_row(row))
+        }
+    };
+    ( render $name:ident () () {} () => () $text:literal ) => {
+        fn $name(&self) -> ::rusqlite::Result<::std::string::String> {
+            Ok(::std::string::String::from($text))
+        }
+    };
+    ( render $name:ident ($($gen_type:ident)*) ($($fn_params:tt)+) {$($prologue:tt)*} () => ($($pv:tt $param:ident)+) $($text:tt)+) => {
+        fn $name<$($gen_type : ::rusqlite::ToSql),*>(&self $($fn_params)+) -> ::rusqlite::Result<::std::string::String> {
+            $($prologue)*
+            let mut args = ::std::vec::Vec::<&dyn ::rusqlite::ToSql>::with_capacity($crate::num_args!($($pv $param)+));
+            $crate::dyn_params!(args $($pv $param)+);
+            let mut sql = ::std::string::String::with_capacity($crate::sql_len!($($text)+));
+            let mut i = args.len();
+            $crate::build_sql!(literal sql args i $($text)+);
+            Ok(sql)
+        }
+    };
+    ( ? $name:ident () () {} () => () $text:literal ) => {
         fn $name<F>(&self, mut row_cb: F) -> ::rusqlite::Result<()>
         where F: FnMut(&::rusqlite::Row) -> ::rusqlite::Result<()>
         {
@@ -177,12 +1102,12 @@ macro_rules! impl_method {
             Ok(())
         }
     };
-    ( ? $name:ident () ($($fn_params:tt)+) () => ( $(: $param:ident)+ ) $($text:tt)+) => {
+    ( ? $name:ident () ($($fn_params:tt)+) {$($prologue:tt)*} () => ( $(: $param:ident)+ ) $($text:tt)+) => {
         fn $name<F>(&self $($fn_params)+ , mut row_cb: F) -> ::rusqlite::Result<()>
         where F: FnMut(&::rusqlite::Row) -> ::rusqlite::Result<()>
         {
-            let mut stmt = self.prepare( $crate::sql_literal!( $($param)+ => $($text)+ ) )?;
-            $crate::bind_args!($($param)+ => stmt 1usize);
+            $($prologue)*
+            $crate::prepare_stmt!(self stmt ($(: $param)+) $($text)+);
             let mut rows = stmt.raw_query();
             while let Some(row) = rows.next()? {
                 row_cb(row)?;
@@ -190,51 +1115,45 @@ macro_rules! impl_method {
             Ok(())
         }
     };
-    ( ? $name:ident ($($gen_type:ident)*) ($($fn_params:tt)+) () => ($($pv:tt $param:ident)+) $($text:tt)+) => {
+    ( ? $name:ident ($($gen_type:ident)*) ($($fn_params:tt)+) {$($prologue:tt)*} () => ($($pv:tt $param:ident)+) $($text:tt)+) => {
         fn $name<$($gen_type : ::rusqlite::ToSql ,)* F>(&self $($fn_params)+, mut row_cb: F) -> ::rusqlite::Result<()>
         where F: FnMut(&::rusqlite::Row) -> ::rusqlite::Result<()>
         {
-            let mut sql = ::std::string::String::with_capacity($crate::sql_len!($($text)+));
-            let mut args = ::std::vec::Vec::<&dyn ::rusqlite::ToSql>::with_capacity($crate::num_args!($($pv $param)+));
-            let mut i = 0;
-            $crate::dynamic_sql!(sql args i $($text)+);
-            let mut stmt = self.prepare(&sql)?;
-            let mut rows = stmt.query(args.as_slice())?;
+            $($prologue)*
+            $crate::prepare_stmt!(self stmt ($($pv $param)+) $($text)+);
+            let mut rows = stmt.raw_query();
             while let Some(row) = rows.next()? {
                 row_cb(row)?;
             }
             Ok(())
         }
     };
-    ( ! $name:ident () () () => () $text:literal ) => {
+    ( ! $name:ident () () {} () => () $text:literal ) => {
         fn $name(&self) -> ::rusqlite::Result<usize> {
             let mut stmt = self.prepare( $text )?;
             stmt.raw_execute()
         }
     };
-    ( ! $name:ident () ($($fn_params:tt)+) () => ( $(: $param:ident)+ ) $($text:tt)+) => {
+    ( ! $name:ident () ($($fn_params:tt)+) {$($prologue:tt)*} () => ( $(: $param:ident)+ ) $($text:tt)+) => {
         fn $name(&self $($fn_params)+ ) -> ::rusqlite::Result<usize> {
-            let mut stmt = self.prepare( $crate::sql_literal!( $($param)+ => $($text)+ ) )?;
-            $crate::bind_args!($($param)+ => stmt 1usize);
+            $($prologue)*
+            $crate::prepare_stmt!(self stmt ($(: $param)+) $($text)+);
             stmt.raw_execute()
         }
     };
-    ( ! $name:ident ($($gen_type:ident)*) ($($fn_params:tt)+) () => ($($pv:tt $param:ident)+) $($text:tt)+) => {
+    ( ! $name:ident ($($gen_type:ident)*) ($($fn_params:tt)+) {$($prologue:tt)*} () => ($($pv:tt $param:ident)+) $($text:tt)+) => {
         fn $name<$($gen_type : ::rusqlite::ToSql),*>(&self $($fn_params)+ ) -> ::rusqlite::Result<usize> {
-            let mut sql = ::std::string::String::with_capacity($crate::sql_len!($($text)+));
-            let mut args = ::std::vec::Vec::<&dyn ::rusqlite::ToSql>::with_capacity($crate::num_args!($($pv $param)+));
-            let mut i = 0;
-            $crate::dynamic_sql!(sql args i $($text)+);
-            let mut stmt = self.prepare(&sql)?;
-            stmt.execute(args.as_slice())
+            $($prologue)*
+            $crate::prepare_stmt!(self stmt ($($pv $param)+) $($text)+);
+            stmt.raw_execute()
         }
     };
-    ( & $name:ident () () () => () $text:literal ) => {
+    ( & $name:ident () () {} () => () $text:literal ) => {
         fn $name(&self) -> ::rusqlite::Result<()> {
             self.execute_batch( $text )
         }
     };
-    ( -> $name:ident () () () => () $text:literal ) => {
+    ( -> $name:ident () () {} () => () $text:literal ) => {
         fn $name<F,R>(&self, row_cb: F) -> ::rusqlite::Result<R>
         where F: FnOnce(&::rusqlite::Row) -> ::rusqlite::Result<R>
         {
@@ -246,12 +1165,12 @@ macro_rules! impl_method {
             }
         }
     };
-    ( -> $name:ident () ($($fn_params:tt)+) () => ( $(: $param:ident)+ ) $($text:tt)+) => {
+    ( -> $name:ident () ($($fn_params:tt)+) {$($prologue:tt)*} () => ( $(: $param:ident)+ ) $($text:tt)+) => {
         fn $name<F,R>(&self $($fn_params)+ , row_cb: F) -> ::rusqlite::Result<R>
         where F: FnOnce(&::rusqlite::Row) -> ::rusqlite::Result<R>
         {
-            let mut stmt = self.prepare( $crate::sql_literal!( $($param)+ => $($text)+ ) )?;
-            $crate::bind_args!($($param)+ => stmt 1usize);
+            $($prologue)*
+            $crate::prepare_stmt!(self stmt ($(: $param)+) $($text)+);
             let mut rows = stmt.raw_query();
             match rows.next()? {
                 Some(row) => row_cb(row),
@@ -259,67 +1178,208 @@ macro_rules! impl_method {
             }
         }
     };
-    ( -> $name:ident ($($gen_type:ident)*) ($($fn_params:tt)+) () => ($($pv:tt $param:ident)+) $($text:tt)+) => {
+    ( -> $name:ident ($($gen_type:ident)*) ($($fn_params:tt)+) {$($prologue:tt)*} () => ($($pv:tt $param:ident)+) $($text:tt)+) => {
         fn $name<$($gen_type : ::rusqlite::ToSql ,)* F,R>(&self $($fn_params)+, row_cb: F) -> ::rusqlite::Result<R>
         where F: FnOnce(&::rusqlite::Row) -> ::rusqlite::Result<R>
         {
-            let mut sql = ::std::string::String::with_capacity($crate::sql_len!($($text)+));
-            let mut args = ::std::vec::Vec::<&dyn ::rusqlite::ToSql>::with_capacity($crate::num_args!($($pv $param)+));
-            let mut i = 0;
-            $crate::dynamic_sql!(sql args i $($text)+);
-            let mut stmt = self.prepare(&sql)?;
-            let mut rows = stmt.query(args.as_slice())?;
+            $($prologue)*
+            $crate::prepare_stmt!(self stmt ($($pv $param)+) $($text)+);
+            let mut rows = stmt.raw_query();
             match rows.next()? {
                 Some(row) => row_cb(row),
                 _ => Err(::rusqlite::Error::QueryReturnedNoRows)
             }
         }
     };
-    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) ($param:ident : _ $($tail:tt)*) => ($($pv:tt $param_name:ident)+) $($text:tt)+)  => {
+    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} ($param:ident : _ $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+)  => {
         $crate::impl_method!{
             $kind
             $name
             ($($gen_type)*)
             ($($fn_params)* , $param : impl ::rusqlite::ToSql)
+            {$($prologue)*}
+            ($($tail)*)
+            =>
+            ($($pv $param_name)* : $param)
+            $($text)+
+        }
+    };
+    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} ($param:ident : (ident < $ptype:ty >) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+)  => {
+        $crate::impl_method!{
+            $kind
+            $name
+            ($($gen_type)*)
+            ($($fn_params)* , $param : $ptype)
+            {$($prologue)*}
+            ($($tail)*)
+            =>
+            ($($pv $param_name)* % $param)
+            $($text)+
+        }
+    };
+    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} ($param:ident : (default < $ptype:ty , $value:literal >) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+)  => {
+        $crate::impl_method!{
+            $kind
+            $name
+            ($($gen_type)*)
+            ($($fn_params)* , $param : ::std::option::Option<$ptype>)
+            {$($prologue)* let $param : $ptype = $param.unwrap_or($value);}
+            ($($tail)*)
+            =>
+            ($($pv $param_name)* : $param)
+            $($text)+
+        }
+    };
+    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} ($param:ident : (into < $ptype:ty >) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+)  => {
+        $crate::impl_method!{
+            $kind
+            $name
+            ($($gen_type)*)
+            ($($fn_params)* , $param : impl ::std::convert::Into<$ptype>)
+            {$($prologue)* let $param : $ptype = $param.into();}
+            ($($tail)*)
+            =>
+            ($($pv $param_name)* : $param)
+            $($text)+
+        }
+    };
+    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} ($param:ident : (as_ref < $ptype:ty >) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+)  => {
+        $crate::impl_method!{
+            $kind
+            $name
+            ($($gen_type)*)
+            ($($fn_params)* , $param : impl ::std::convert::AsRef<$ptype>)
+            {$($prologue)* let $param : &$ptype = $param.as_ref();}
+            ($($tail)*)
+            =>
+            ($($pv $param_name)* : $param)
+            $($text)+
+        }
+    };
+    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} ($param:ident : (range < $ptype:ty , $min:literal , $max:literal >) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+)  => {
+        $crate::impl_method!{
+            $kind
+            $name
+            ($($gen_type)*)
+            ($($fn_params)* , $param : $ptype)
+            {
+                $($prologue)*
+                if !($min ..= $max).contains(&$param) {
+                    $crate::invalid_argument!($name $param ::std::concat!("is not between ", $min, " and ", $max));
+                }
+            }
+            ($($tail)*)
+            =>
+            ($($pv $param_name)* : $param)
+            $($text)+
+        }
+    };
+    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} ($param:ident : (len < $min:literal , $max:literal >) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+)  => {
+        $crate::impl_method!{
+            $kind
+            $name
+            ($($gen_type)*)
+            ($($fn_params)* , $param : &str)
+            {
+                $($prologue)*
+                if !($min ..= $max).contains(&$param.chars().count()) {
+                    $crate::invalid_argument!($name $param ::std::concat!("length is not between ", $min, " and ", $max));
+                }
+            }
+            ($($tail)*)
+            =>
+            ($($pv $param_name)* : $param)
+            $($text)+
+        }
+    };
+    ( render $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} ($param:ident : (sensitive < $ptype:ty >) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+)  => {
+        $crate::impl_method!{
+            render
+            $name
+            ($($gen_type)*)
+            ($($fn_params)* , $param : $ptype)
+            {$($prologue)* let _ = &$param; let $param = "***";}
+            ($($tail)*)
+            =>
+            ($($pv $param_name)* : $param)
+            $($text)+
+        }
+    };
+    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} ($param:ident : (sensitive < $ptype:ty >) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+)  => {
+        $crate::impl_method!{
+            $kind
+            $name
+            ($($gen_type)*)
+            ($($fn_params)* , $param : $ptype)
+            {$($prologue)*}
             ($($tail)*)
             =>
-            ($($pv $param_name)+)
+            ($($pv $param_name)* : $param)
             $($text)+
         }
     };
-    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) ($param:ident : ($ptype:ty) $($tail:tt)*) => ($($pv:tt $param_name:ident)+) $($text:tt)+)  => {
+    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} ($param:ident : ($ptype:ty) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+)  => {
         $crate::impl_method!{
             $kind
             $name
             ($($gen_type)*)
             ($($fn_params)* , $param : $ptype)
+            {$($prologue)*}
             ($($tail)*)
             =>
-            ($($pv $param_name)+)
+            ($($pv $param_name)* : $param)
             $($text)+
         }
     };
-    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) ($param:ident # [$gtype:ident] $($tail:tt)*) => ($($pv:tt $param_name:ident)+) $($text:tt)+)  => {
+    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} ($param:ident # [$gtype:ident] $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+)  => {
         $crate::impl_method!{
             $kind
             $name
             ($($gen_type)* $gtype)
             ($($fn_params)* , $param : & [ $gtype ])
+            {$($prologue)*}
+            ($($tail)*)
+            =>
+            ($($pv $param_name)* # $param)
+            $($text)+
+        }
+    };
+    ( render $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} ($param:ident # (sensitive < $ptype:ty >) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+)  => {
+        $crate::impl_method!{
+            render
+            $name
+            ($($gen_type)*)
+            ($($fn_params)* , $param : & [ $ptype ])
+            {$($prologue)* let masked = ::std::vec!["***"; $param.len()]; let $param = masked.as_slice();}
+            ($($tail)*)
+            =>
+            ($($pv $param_name)* # $param)
+            $($text)+
+        }
+    };
+    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} ($param:ident # (sensitive < $ptype:ty >) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+)  => {
+        $crate::impl_method!{
+            $kind
+            $name
+            ($($gen_type)*)
+            ($($fn_params)* , $param : & [ $ptype ])
+            {$($prologue)*}
             ($($tail)*)
             =>
-            ($($pv $param_name)+)
+            ($($pv $param_name)* # $param)
             $($text)+
         }
     };
-    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) ($param:ident # ($ptype:ty) $($tail:tt)*) => ($($pv:tt $param_name:ident)+) $($text:tt)+)  => {
+    ( $kind:tt $name:ident ($($gen_type:ident)*) ($($fn_params:tt)*) {$($prologue:tt)*} ($param:ident # ($ptype:ty) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+)  => {
         $crate::impl_method!{
             $kind
             $name
             ($($gen_type)*)
             ($($fn_params)* , $param : & [ $ptype ])
+            {$($prologue)*}
             ($($tail)*)
             =>
-            ($($pv $param_name)+)
+            ($($pv $param_name)* # $param)
             $($text)+
         }
     };
@@ -327,76 +1387,944 @@ macro_rules! impl_method {
 
 #[macro_export]
 #[doc(hidden)]
-macro_rules! sql_literal {
-    ($($name:ident)+ => $text:literal) => {
-        $text
+macro_rules! decl_extra_methods {
+    ( [] $sql_name:ident $kind:tt $name:ident $doc:literal $params:tt ) => {};
+    ( [with $($extra:ident)*] $sql_name:ident $kind:tt $name:ident $doc:literal $params:tt ) => {
+        $crate::decl_serde_method!{ $kind $name $doc $params }
+        $crate::decl_extra_methods!{ [$($extra)*] $sql_name $kind $name $doc $params }
     };
-    ($($name:ident)+ => $text:literal : $param:ident) => {
-        ::std::concat!( $text, '?', $crate::index_of!($param in [ $( $name ),+ ] + 1) )
+    ( [rows $($extra:ident)*] $sql_name:ident $kind:tt $name:ident $doc:literal $params:tt ) => {
+        $crate::decl_from_row_methods!{ $sql_name $kind $name $doc $params }
+        $crate::decl_extra_methods!{ [$($extra)*] $sql_name $kind $name $doc $params }
     };
-    ($($name:ident)+ => $text:literal : $param:ident $($tail:tt)+) => {
-        ::std::concat!(
-            $text, '?', $crate::index_of!($param in [ $( $name ),+ ] + 1),
-            $crate::sql_literal!($($name)+ => $($tail)+)
-        )
+    ( [tuples $($extra:ident)*] $sql_name:ident $kind:tt $name:ident $doc:literal $params:tt ) => {
+        $crate::decl_tuples_method!{ $sql_name $kind $name $doc $params }
+        $crate::decl_extra_methods!{ [$($extra)*] $sql_name $kind $name $doc $params }
+    };
+    ( [deserialized $($extra:ident)*] $sql_name:ident $kind:tt $name:ident $doc:literal $params:tt ) => {
+        $crate::decl_deserialized_method!{ $sql_name $kind $name $doc $params }
+        $crate::decl_extra_methods!{ [$($extra)*] $sql_name $kind $name $doc $params }
+    };
+    ( [render $($extra:ident)*] $sql_name:ident $kind:tt $name:ident $doc:literal $params:tt ) => {
+        $crate::decl_render_method!{ $name $doc $params }
+        $crate::decl_extra_methods!{ [$($extra)*] $sql_name $kind $name $doc $params }
+    };
+    ( [$unknown:ident $($extra:ident)*] $sql_name:ident $kind:tt $name:ident $doc:literal $params:tt ) => {
+        ::std::compile_error!(::std::concat!("unknown extra methods `", ::std::stringify!($unknown), "`; expected `with`, `rows`, `tuples`, `deserialized` or `render`"));
     };
 }
 
 #[macro_export]
 #[doc(hidden)]
-macro_rules! bind_args {
-    ($head:ident $($tail:ident)* => $stmt:ident $idx:expr) => {
-        $stmt.raw_bind_parameter($idx, $head)?;
-        $crate::bind_args!($($tail)* => $stmt $idx+1usize);
+macro_rules! impl_extra_methods {
+    ( [] $sql_name:ident $kind:tt $name:ident $params:tt $($text:tt)+ ) => {};
+    ( [with $($extra:ident)*] $sql_name:ident $kind:tt $name:ident $params:tt $($text:tt)+ ) => {
+        $crate::impl_serde_method!{ $kind $name $params $($text)+ }
+        $crate::impl_extra_methods!{ [$($extra)*] $sql_name $kind $name $params $($text)+ }
     };
-    (=> $stmt:ident $idx:expr) => {};
+    ( [rows $($extra:ident)*] $sql_name:ident $kind:tt $name:ident $params:tt $($text:tt)+ ) => {
+        $crate::impl_from_row_methods!{ $sql_name $kind $name $params $($text)+ }
+        $crate::impl_extra_methods!{ [$($extra)*] $sql_name $kind $name $params $($text)+ }
+    };
+    ( [tuples $($extra:ident)*] $sql_name:ident $kind:tt $name:ident $params:tt $($text:tt)+ ) => {
+        $crate::impl_tuples_method!{ $sql_name $kind $name $params $($text)+ }
+        $crate::impl_extra_methods!{ [$($extra)*] $sql_name $kind $name $params $($text)+ }
+    };
+    ( [deserialized $($extra:ident)*] $sql_name:ident $kind:tt $name:ident $params:tt $($text:tt)+ ) => {
+        $crate::impl_deserialized_method!{ $sql_name $kind $name $params $($text)+ }
+        $crate::impl_extra_methods!{ [$($extra)*] $sql_name $kind $name $params $($text)+ }
+    };
+    ( [render $($extra:ident)*] $sql_name:ident $kind:tt $name:ident $params:tt $($text:tt)+ ) => {
+        $crate::impl_render_method!{ $name $params $($text)+ }
+        $crate::impl_extra_methods!{ [$($extra)*] $sql_name $kind $name $params $($text)+ }
+    };
+    ( [$unknown:ident $($extra:ident)*] $sql_name:ident $kind:tt $name:ident $params:tt $($text:tt)+ ) => {};
 }
 
+#[cfg(feature = "serde")]
 #[macro_export]
 #[doc(hidden)]
-macro_rules! num_args {
-    () => { 0 };
-    (: $head:ident $($tail:tt)*) => { 1 + $crate::num_args!($($tail)*) };
-    (# $head:ident $($tail:tt)*) => { $head.len() + $crate::num_args!($($tail)*) };
+macro_rules! decl_serde_method {
+    ( $kind:tt $name:ident $doc:literal () ) => {};
+    ( $kind:tt $name:ident $doc:literal ($($params:tt)+) ) => {
+        $crate::decl_serde_method!{ $kind $name $doc [] $($params)+ }
+    };
+    ( $kind:tt $name:ident $doc:literal [] $param:ident : (ident < $ptype:ty >) $($tail:tt)* ) => {};
+    ( $kind:tt $name:ident $doc:literal [] $param:ident : (range < $ptype:ty , $min:literal , $max:literal >) $($tail:tt)* ) => {};
+    ( $kind:tt $name:ident $doc:literal [] $param:ident : (len < $min:literal , $max:literal >) $($tail:tt)* ) => {};
+    ( $kind:tt $name:ident $doc:literal [] $param:ident $pv:tt $ptype:tt $($tail:tt)* ) => {
+        $crate::decl_serde_method!{ $kind $name $doc [] $($tail)* }
+    };
+    ( $kind:tt $name:ident $doc:literal [] ) => {
+        $crate::paste::paste!{
+            $crate::decl_method!{ $kind [<$name _with>] $doc () (, params: &impl $crate::serde::Serialize) }
+        }
+    };
 }
 
+#[cfg(not(feature = "serde"))]
 #[macro_export]
 #[doc(hidden)]
-macro_rules! sql_len {
-    () => { 0 };
-    ($text:literal $($tail:tt)*) => { $text.len() + $crate::sql_len!($($tail)*) };
-    (: $head:ident $($tail:tt)*) => { 3 + $crate::sql_len!($($tail)*) };
-    (# $head:ident $($tail:tt)*) => { $head.len() * 5 + $crate::sql_len!($($tail)*) };
+macro_rules! decl_serde_method {
+    ($($any:tt)*) => {
+        ::std::compile_error!("`with` methods require the `serde` feature of include-sqlite-sql");
+    };
 }
 
+#[cfg(feature = "serde")]
 #[macro_export]
 #[doc(hidden)]
-macro_rules! dynamic_sql {
-    ($stmt:ident $args:ident $i:ident) => {};
-    ($stmt:ident $args:ident $i:ident $text:literal $($tail:tt)*) => {
-        $stmt.push_str($text);
-        $crate::dynamic_sql!($stmt $args $i $($tail)*);
-    };
-    ($stmt:ident $args:ident $i:ident : $param:ident $($tail:tt)*) => {
-        $i += 1;
-        $stmt.push_str(&::std::format!("?{}", $i));
-        $args.push(&$param);
-        $crate::dynamic_sql!($stmt $args $i $($tail)*);
+macro_rules! impl_serde_method {
+    ( $kind:tt $name:ident () $($text:tt)+ ) => {};
+    ( $kind:tt $name:ident ($($params:tt)+) $($text:tt)+ ) => {
+        $crate::impl_serde_method!{ $kind $name params {} ($($params)+) => () $($text)+ }
     };
-    ($stmt:ident $args:ident $i:ident # $param:ident $($tail:tt)*) => {
-        let mut iter = $param.into_iter();
-        if let Some(arg) = iter.next() {
-            $i += 1;
-            $stmt.push_str(&::std::format!("?{}", $i));
-            $args.push(arg);
-            while let Some(arg) = iter.next() {
-                $i += 1;
-                $stmt.push_str(&::std::format!(", ?{}", $i));
-                $args.push(arg);
+    ( $kind:tt $name:ident $args:ident {$($prologue:tt)*} ($param:ident : (ident < $ptype:ty >) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+ ) => {};
+    ( $kind:tt $name:ident $args:ident {$($prologue:tt)*} ($param:ident : (range < $ptype:ty , $min:literal , $max:literal >) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+ ) => {};
+    ( $kind:tt $name:ident $args:ident {$($prologue:tt)*} ($param:ident : (len < $min:literal , $max:literal >) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+ ) => {};
+    ( $kind:tt $name:ident $args:ident {$($prologue:tt)*} ($param:ident : (default < $ptype:ty , $value:literal >) $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+ ) => {
+        $crate::impl_serde_method!{
+            $kind
+            $name
+            $args
+            {
+                $($prologue)*
+                let $param = $args.get::<::rusqlite::types::Value>(::std::stringify!($param));
+                let $param : &dyn ::rusqlite::ToSql = match &$param { Some(value) => value, None => &$value };
             }
-        } else {
+            ($($tail)*)
+            =>
+            ($($pv $param_name)* : $param)
+            $($text)+
+        }
+    };
+    ( $kind:tt $name:ident $args:ident {$($prologue:tt)*} ($param:ident : $ptype:tt $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+ ) => {
+        $crate::impl_serde_method!{
+            $kind
+            $name
+            $args
+            {
+                $($prologue)*
+                let $param = $args.value::<::rusqlite::types::Value>(::std::stringify!($param))
+                    .map_err(|err| ::rusqlite::Error::ToSqlConversionFailure(::std::boxed::Box::new(err)))?;
+            }
+            ($($tail)*)
+            =>
+            ($($pv $param_name)* : $param)
+            $($text)+
+        }
+    };
+    ( $kind:tt $name:ident $args:ident {$($prologue:tt)*} ($param:ident # $ptype:tt $($tail:tt)*) => ($($pv:tt $param_name:ident)*) $($text:tt)+ ) => {
+        $crate::impl_serde_method!{
+            $kind
+            $name
+            $args
+            {
+                $($prologue)*
+                let $param = $args.list::<::rusqlite::types::Value>(::std::stringify!($param))
+                    .map_err(|err| ::rusqlite::Error::ToSqlConversionFailure(::std::boxed::Box::new(err)))?;
+                let $param = &$param;
+            }
+            ($($tail)*)
+            =>
+            ($($pv $param_name)* # $param)
+            $($text)+
+        }
+    };
+    ( $kind:tt $name:ident $args:ident {$($prologue:tt)*} () => ($($pv:tt $param_name:ident)+) $($text:tt)+ ) => {
+        $crate::paste::paste!{
+            $crate::impl_method!{
+                $kind
+                [<$name _with>]
+                ()
+                (, $args: &impl $crate::serde::Serialize)
+                {
+                    let $args = $crate::params::Params::new($args)
+                        .map_err(|err| ::rusqlite::Error::ToSqlConversionFailure(::std::boxed::Box::new(err)))?;
+                    $($prologue)*
+                }
+                ()
+                =>
+                ($($pv $param_name)+)
+                $($text)+
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_serde_method {
+    ($($any:tt)*) => {};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! sql_literal {
+    ($($name:ident)+ => $text:literal) => {
+        $text
+    };
+    ($($name:ident)+ => $text:literal : $param:ident) => {
+        ::std::concat!( $text, $crate::placeholder!($param in [ $( $name ),+ ]) )
+    };
+    ($($name:ident)+ => $text:literal : $param:ident $($tail:tt)+) => {
+        ::std::concat!(
+            $text, $crate::placeholder!($param in [ $( $name ),+ ]),
+            $crate::sql_literal!($($name)+ => $($tail)+)
+        )
+    };
+}
+
+#[cfg(not(feature = "named-params"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! placeholder {
+    ($param:ident in [ $( $name:ident ),+ ]) => {
+        ::std::concat!( '?', $crate::index_of!($param in [ $( $name ),+ ] + 1) )
+    };
+}
+
+#[cfg(feature = "named-params")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! placeholder {
+    ($param:ident in [ $( $name:ident ),+ ]) => {
+        ::std::concat!( ':', ::std::stringify!($param) )
+    };
+}
+
+#[cfg(not(feature = "named-params"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! bind_args {
+    ($head:ident $($tail:ident)* => $stmt:ident $idx:expr) => {
+        $stmt.raw_bind_parameter($idx, $head)?;
+        $crate::bind_args!($($tail)* => $stmt $idx+1usize);
+    };
+    (=> $stmt:ident $idx:expr) => {};
+}
+
+#[cfg(feature = "named-params")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! bind_args {
+    ($head:ident $($tail:ident)* => $stmt:ident $idx:expr) => {
+        $stmt.raw_bind_parameter($crate::param_index!($stmt ::std::concat!(':', ::std::stringify!($head))), $head)?;
+        $crate::bind_args!($($tail)* => $stmt $idx);
+    };
+    (=> $stmt:ident $idx:expr) => {};
+}
+
+#[cfg(feature = "named-params")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! param_index {
+    ($stmt:ident $name:expr) => {
+        match $stmt.parameter_index($name)? {
+            Some(idx) => idx,
+            None => return Err(::rusqlite::Error::InvalidParameterName(::std::string::String::from($name))),
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! decl_sql_const {
+    ( $name:ident () $text:literal ) => {
+        $crate::decl_sql_const!{ @emit $name false $text }
+    };
+    ( $name:ident ($($params:tt)+) $($text:tt)+ ) => {
+        $crate::decl_sql_const!{ @scan $name [] ($($params)+) $($text)+ }
+    };
+    ( @scan $name:ident [$($scalar:ident)*] ($param:ident : (ident < $ptype:ty >) $($tail:tt)*) $($text:tt)+ ) => {
+        $crate::decl_sql_const!{ @emit $name true $crate::source_sql!($($text)+) }
+    };
+    ( @scan $name:ident [$($scalar:ident)*] ($param:ident # $ptype:tt $($tail:tt)*) $($text:tt)+ ) => {
+        $crate::decl_sql_const!{ @emit $name true $crate::source_sql!($($text)+) }
+    };
+    ( @scan $name:ident [$($scalar:ident)*] ($param:ident : $ptype:tt $($tail:tt)*) $($text:tt)+ ) => {
+        $crate::decl_sql_const!{ @scan $name [$($scalar)* $param] ($($tail)*) $($text)+ }
+    };
+    ( @scan $name:ident [$($scalar:ident)+] () $($text:tt)+ ) => {
+        $crate::decl_sql_const!{ @emit $name false $crate::sql_literal!($($scalar)+ => $($text)+) }
+    };
+    ( @emit $name:ident $dynamic:literal $sql:expr ) => {
+        $crate::paste::paste!{
+            /// SQL of the statement as it is prepared, or as it was included if the statement is dynamic
+            const [<$name:upper _SQL>]: &'static str = $sql;
+            /// Whether the statement SQL is assembled at run time
+            const [<$name:upper _DYNAMIC>]: bool = $dynamic;
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! prepare_stmt {
+    ( $conn:ident $stmt:ident () $text:literal ) => {
+        let mut $stmt = $conn.prepare( $text )?;
+    };
+    ( $conn:ident $stmt:ident ($(: $param:ident)+) $($text:tt)+ ) => {
+        let mut $stmt = $conn.prepare( $crate::sql_literal!( $($param)+ => $($text)+ ) )?;
+        $crate::bind_args!($($param)+ => $stmt 1usize);
+    };
+    ( $conn:ident $stmt:ident ($($pv:tt $param:ident)+) $($text:tt)+ ) => {
+        let mut args = ::std::vec::Vec::<&dyn ::rusqlite::ToSql>::with_capacity($crate::num_args!($($pv $param)+));
+        $crate::dyn_params!(args $($pv $param)+);
+        let mut $stmt = match $crate::in_list_sql!(($($pv $param)+) $($text)+) {
+            ::std::option::Option::Some(sql) => {
+                $crate::list_args!(args $($pv $param)+);
+                $conn.prepare(sql)?
+            }
+            ::std::option::Option::None => {
+                let mut sql = ::std::string::String::with_capacity($crate::sql_len!($($text)+));
+                let mut i = args.len();
+                $crate::dynamic_sql!(sql args i $($text)+);
+                $conn.prepare(&sql)?
+            }
+        };
+        $crate::bind_dyn_args!($stmt args $($pv $param)+);
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! check_stmt {
+    ( $conn:ident $errors:ident & $name:ident $params:tt $($text:tt)+ ) => {};
+    ( $conn:ident $errors:ident $kind:tt $name:ident ($($params:tt)*) $($text:tt)+ ) => {
+        $crate::check_stmt!{ @scan $conn $errors $name [] [] ($($params)*) $($text)+ }
+    };
+    ( @scan $conn:ident $errors:ident $name:ident [$($pv:tt $param_name:ident)*] [$($ident:tt)*] ($param:ident : (ident < $ptype:ty >) $($tail:tt)*) $($text:tt)+ ) => {
+        $crate::check_stmt!{ @scan $conn $errors $name [$($pv $param_name)* % $param] [$($ident)* ($param $ptype)] ($($tail)*) $($text)+ }
+    };
+    ( @scan $conn:ident $errors:ident $name:ident [$($pv:tt $param_name:ident)*] [$($ident:tt)*] ($param:ident : $ptype:tt $($tail:tt)*) $($text:tt)+ ) => {
+        $crate::check_stmt!{ @scan $conn $errors $name [$($pv $param_name)* : $param] [$($ident)*] ($($tail)*) $($text)+ }
+    };
+    ( @scan $conn:ident $errors:ident $name:ident [$($pv:tt $param_name:ident)*] [$($ident:tt)*] ($param:ident # $ptype:tt $($tail:tt)*) $($text:tt)+ ) => {
+        $crate::check_stmt!{ @scan $conn $errors $name [$($pv $param_name)* # $param] [$($ident)*] ($($tail)*) $($text)+ }
+    };
+    ( @scan $conn:ident $errors:ident $name:ident [] [] () $text:literal ) => {
+        if let Err(err) = $conn.prepare($text) {
+            $errors.push((::std::stringify!($name), err));
+        }
+    };
+    ( @scan $conn:ident $errors:ident $name:ident [$(: $param:ident)+] [] () $($text:tt)+ ) => {
+        if let Err(err) = $conn.prepare($crate::sql_literal!($($param)+ => $($text)+)) {
+            $errors.push((::std::stringify!($name), err));
+        }
+    };
+    ( @scan $conn:ident $errors:ident $name:ident [$($pv:tt $param:ident)+] [$($ident:tt)*] () $($text:tt)+ ) => {
+        let check = || -> ::rusqlite::Result<()> {
+            $crate::check_stmt!{ @expand $conn [$($ident)*] ($($pv $param)+) $($text)+ }
+            Ok(())
+        };
+        if let Err(err) = check() {
+            $errors.push((::std::stringify!($name), err));
+        }
+    };
+    ( @expand $conn:ident [($ident:ident $ptype:ty) $($tail:tt)*] ($($pv:tt $param:ident)+) $($text:tt)+ ) => {
+        for &$ident in <$ptype as $crate::SqlIdent>::IDENTS {
+            $crate::check_stmt!{ @expand $conn [$($tail)*] ($($pv $param)+) $($text)+ }
+        }
+    };
+    ( @expand $conn:ident [] ($($pv:tt $param:ident)+) $($text:tt)+ ) => {
+        let mut args = ::std::vec::Vec::<&dyn ::rusqlite::ToSql>::new();
+        $crate::check_params!(args $($pv $param)+);
+        let mut sql = ::std::string::String::with_capacity($crate::sql_len!($($text)+));
+        let mut i = args.len();
+        $crate::dynamic_sql!(sql args i $($text)+);
+        $conn.prepare(&sql)?;
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! check_params {
+    ($args:ident) => {};
+    ($args:ident : $param:ident $($tail:tt)*) => {
+        $args.push(&::rusqlite::types::Null);
+        let $param = $crate::DynParam::Bind($args.len());
+        $crate::check_params!($args $($tail)*);
+    };
+    ($args:ident % $param:ident $($tail:tt)*) => {
+        let $param = $crate::DynParam::Ident($param);
+        $crate::check_params!($args $($tail)*);
+    };
+    ($args:ident # $param:ident $($tail:tt)*) => {
+        let $param : &[::rusqlite::types::Null] = &[::rusqlite::types::Null];
+        $crate::check_params!($args $($tail)*);
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! decl_from_row_methods {
+    ( $sql_name:ident ? $name:ident $doc:literal ($($params:tt)*) ) => {
+        $crate::paste::paste!{
+            $crate::decl_method!{ (rows $sql_name $name) [<$name _rows>] $doc () () $($params)* }
+            $crate::decl_method!{ (row ? $sql_name $name) [<$name _row>] $doc () () $($params)* }
+        }
+    };
+    ( $sql_name:ident -> $name:ident $doc:literal ($($params:tt)*) ) => {
+        $crate::paste::paste!{
+            $crate::decl_method!{ (row -> $sql_name $name) [<$name _row>] $doc () () $($params)* }
+        }
+    };
+    ( $sql_name:ident $kind:tt $name:ident $doc:literal ($($params:tt)*) ) => {};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_from_row_methods {
+    ( $sql_name:ident ? $name:ident ($($params:tt)*) $($text:tt)+ ) => {
+        $crate::paste::paste!{
+            $crate::impl_method!{ (rows $sql_name $name) [<$name _rows>] () () {} ($($params)*) => () $($text)+ }
+            $crate::impl_method!{ (row ? $sql_name $name) [<$name _row>] () () {} ($($params)*) => () $($text)+ }
+        }
+    };
+    ( $sql_name:ident -> $name:ident ($($params:tt)*) $($text:tt)+ ) => {
+        $crate::paste::paste!{
+            $crate::impl_method!{ (row -> $sql_name $name) [<$name _row>] () () {} ($($params)*) => () $($text)+ }
+        }
+    };
+    ( $sql_name:ident $kind:tt $name:ident ($($params:tt)*) $($text:tt)+ ) => {};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! decl_tuples_method {
+    ( $sql_name:ident ? $name:ident $doc:literal ($($params:tt)*) ) => {
+        $crate::paste::paste!{
+            $crate::decl_method!{ (tuples $sql_name $name) [<$name _tuples>] $doc () () $($params)* }
+        }
+    };
+    ( $sql_name:ident $kind:tt $name:ident $doc:literal ($($params:tt)*) ) => {};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_tuples_method {
+    ( $sql_name:ident ? $name:ident ($($params:tt)*) $($text:tt)+ ) => {
+        $crate::paste::paste!{
+            $crate::impl_method!{ (tuples $sql_name $name) [<$name _tuples>] () () {} ($($params)*) => () $($text)+ }
+        }
+    };
+    ( $sql_name:ident $kind:tt $name:ident ($($params:tt)*) $($text:tt)+ ) => {};
+}
+
+#[cfg(feature = "serde")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! decl_deserialized_method {
+    ( $sql_name:ident ? $name:ident $doc:literal ($($params:tt)*) ) => {
+        $crate::paste::paste!{
+            $crate::decl_method!{ (de ? $sql_name $name) [<$name _deserialized>] $doc () () $($params)* }
+        }
+    };
+    ( $sql_name:ident -> $name:ident $doc:literal ($($params:tt)*) ) => {
+        $crate::paste::paste!{
+            $crate::decl_method!{ (de -> $sql_name $name) [<$name _deserialized>] $doc () () $($params)* }
+        }
+    };
+    ( $sql_name:ident $kind:tt $name:ident $doc:literal ($($params:tt)*) ) => {};
+}
+
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! decl_deserialized_method {
+    ($($any:tt)*) => {
+        ::std::compile_error!("`deserialized` methods require the `serde` feature of include-sqlite-sql");
+    };
+}
+
+#[cfg(feature = "serde")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_deserialized_method {
+    ( $sql_name:ident ? $name:ident ($($params:tt)*) $($text:tt)+ ) => {
+        $crate::paste::paste!{
+            $crate::impl_method!{ (de ? $sql_name $name) [<$name _deserialized>] () () {} ($($params)*) => () $($text)+ }
+        }
+    };
+    ( $sql_name:ident -> $name:ident ($($params:tt)*) $($text:tt)+ ) => {
+        $crate::paste::paste!{
+            $crate::impl_method!{ (de -> $sql_name $name) [<$name _deserialized>] () () {} ($($params)*) => () $($text)+ }
+        }
+    };
+    ( $sql_name:ident $kind:tt $name:ident ($($params:tt)*) $($text:tt)+ ) => {};
+}
+
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_deserialized_method {
+    ($($any:tt)*) => {};
+}
+
+#[cfg(feature = "serde")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! deserialize_row {
+    ($row:ident) => {{
+        let stmt = $row.as_ref();
+        let mut columns = ::std::vec::Vec::with_capacity(stmt.column_count());
+        for idx in 0..stmt.column_count() {
+            let value = match $row.get_ref(idx)? {
+                ::rusqlite::types::ValueRef::Null => $crate::rows::Column::Null,
+                ::rusqlite::types::ValueRef::Integer(val) => $crate::rows::Column::Integer(val),
+                ::rusqlite::types::ValueRef::Real(val) => $crate::rows::Column::Real(val),
+                ::rusqlite::types::ValueRef::Text(text) => $crate::rows::Column::Text(text),
+                ::rusqlite::types::ValueRef::Blob(blob) => $crate::rows::Column::Blob(blob),
+            };
+            columns.push((stmt.column_name(idx)?, value));
+        }
+        $crate::rows::deserialize(columns).map_err(|err| match err {
+            $crate::rows::Error::Column(idx, err) => ::rusqlite::Error::FromSqlConversionFailure(
+                idx, $row.get_ref(idx).map_or(::rusqlite::types::Type::Null, |value| value.data_type()), ::std::boxed::Box::new(err)
+            ),
+            $crate::rows::Error::MissingColumn(name) => ::rusqlite::Error::InvalidColumnName(::std::string::String::from(name)),
+            err => ::rusqlite::Error::SqliteFailure(::rusqlite::ffi::Error::new(::rusqlite::ffi::SQLITE_MISMATCH), ::std::option::Option::Some(err.to_string())),
+        })
+    }};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! query_kind {
+    (?)  => { $crate::QueryKind::Select };
+    (!)  => { $crate::QueryKind::Execute };
+    (&)  => { $crate::QueryKind::Batch };
+    (->) => { $crate::QueryKind::Returning };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! is_list {
+    (:) => { false };
+    (#) => { true };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! is_sensitive {
+    ((sensitive < $ptype:ty >)) => { true };
+    ($ptype:tt) => { false };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! param_type {
+    (_) => { "_" };
+    ([$gtype:ident]) => { "_" };
+    (($ptype:ty)) => { ::std::stringify!($ptype) };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! check_placeholders {
+    ( $name:ident $($text:tt)+ ) => {
+        const _: () = if $crate::find_unsupported_placeholder($crate::source_sql!($($text)+)).is_some() {
+            ::std::panic!(::std::concat!(
+                "statement `", ::std::stringify!($name), "` has `?`, `@name` or `$name` parameters - use `:name` instead"
+            ))
+        };
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! source_sql {
+    ( $([$($acc:tt)*])? ) => {
+        ::std::concat!( $($($acc)*)? )
+    };
+    ( [$($acc:tt)*] $text:literal $($tail:tt)* ) => {
+        $crate::source_sql!( [$($acc)* $text ,] $($tail)* )
+    };
+    ( [$($acc:tt)*] $pv:tt $param:ident $($tail:tt)* ) => {
+        $crate::source_sql!( [$($acc)* ':', ::std::stringify!($param) ,] $($tail)* )
+    };
+    ( $($text:tt)+ ) => {
+        $crate::source_sql!( [] $($text)+ )
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! invalid_argument {
+    ($name:ident $param:ident $constraint:expr) => {
+        return Err(::rusqlite::Error::ToSqlConversionFailure(::std::boxed::Box::new($crate::InvalidArgument {
+            method: ::std::stringify!($name),
+            param: ::std::stringify!($param),
+            constraint: $constraint,
+        })))
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! num_args {
+    () => { 0 };
+    (: $head:ident $($tail:tt)*) => { 1 + $crate::num_args!($($tail)*) };
+    (# $head:ident $($tail:tt)*) => { $head.len() + $crate::num_args!($($tail)*) };
+    (% $head:ident $($tail:tt)*) => { $crate::num_args!($($tail)*) };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! dyn_params {
+    ($args:ident) => {};
+    ($args:ident : $param:ident $($tail:tt)*) => {
+        $args.push(&$param);
+        let $param = $crate::DynParam::Bind($args.len());
+        $crate::dyn_params!($args $($tail)*);
+    };
+    ($args:ident % $param:ident $($tail:tt)*) => {
+        let $param = $crate::DynParam::Ident($crate::SqlIdent::as_sql(&$param));
+        $crate::dyn_params!($args $($tail)*);
+    };
+    ($args:ident # $param:ident $($tail:tt)*) => {
+        $crate::dyn_params!($args $($tail)*);
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! in_list_sql {
+    ( ($($pv:tt $param:ident)+) $($text:tt)+ ) => {
+        $crate::in_list_sql!{ [] [] [] ($($pv $param)+) $($text)+ }
+    };
+    ( [$($scalar:ident)*] [$($list:ident)*] [$($ident:ident)*] (: $param:ident $($tail:tt)*) $($text:tt)+ ) => {
+        $crate::in_list_sql!{ [$($scalar)* $param] [$($list)*] [$($ident)*] ($($tail)*) $($text)+ }
+    };
+    ( [$($scalar:ident)*] [$($list:ident)*] [$($ident:ident)*] (# $param:ident $($tail:tt)*) $($text:tt)+ ) => {
+        $crate::in_list_sql!{ [$($scalar)*] [$($list)* $param] [$($ident)*] ($($tail)*) $($text)+ }
+    };
+    ( [$($scalar:ident)*] [$($list:ident)*] [$($ident:ident)*] (% $param:ident $($tail:tt)*) $($text:tt)+ ) => {
+        $crate::in_list_sql!{ [$($scalar)*] [$($list)*] [$($ident)* $param] ($($tail)*) $($text)+ }
+    };
+    ( [$($scalar:ident)*] [$list:ident] [] () $($text:tt)+ ) => {
+        $crate::in_list_items!{ $list [$($scalar)*] $($text)+ }
+    };
+    ( [$($scalar:ident)*] [$($list:ident)*] [$($ident:ident)*] () $($text:tt)+ ) => {
+        ::std::option::Option::None
+    };
+    ( @items [$($item:tt)+] $list:ident [$($scalar:ident)*] $($text:tt)+ ) => {
+        $crate::in_list_sql!{ @arms $list [$($scalar)*] [$($text)+] [] [] [] [$($item)+] }
+    };
+    ( @arms $list:ident [$($scalar:ident)*] [$($text:tt)+] [$($arms:tt)*] [$($used:tt)*] [$($used_name:ident)*] [($item:ident $num:tt) $($rest:tt)*] ) => {
+        $crate::in_list_sql!{
+            @arms $list [$($scalar)*] [$($text)+]
+            [
+                $($arms)*
+                $num => ::std::option::Option::Some(
+                    $crate::in_list_literal!([] $list [ $($scalar ,)* $($used_name ,)* $item ] [$($used)* ($item $num)] $($text)+)
+                ),
+            ]
+            [$($used)* ($item $num)] [$($used_name)* $item] [$($rest)*]
+        }
+    };
+    ( @arms $list:ident [$($scalar:ident)*] [$($text:tt)+] [$($arms:tt)*] [$($used:tt)*] [$($used_name:ident)*] [] ) => {
+        match $list.len() {
+            0 => ::std::option::Option::Some(
+                $crate::in_list_literal!([] $list [ $($scalar),* ] [] $($text)+)
+            ),
+            $($arms)*
+            _ => ::std::option::Option::None,
+        }
+    };
+}
+
+#[cfg(not(any(feature = "in-list-4", feature = "in-list-8", feature = "in-list-16")))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! in_list_items {
+    ($($args:tt)+) => { ::std::option::Option::None };
+}
+
+#[cfg(all(feature = "in-list-4", not(any(feature = "in-list-8", feature = "in-list-16"))))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! in_list_items {
+    ($($args:tt)+) => {
+        $crate::in_list_sql!{ @items [(_1 1) (_2 2) (_3 3) (_4 4)] $($args)+ }
+    };
+}
+
+#[cfg(all(feature = "in-list-8", not(feature = "in-list-16")))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! in_list_items {
+    ($($args:tt)+) => {
+        $crate::in_list_sql!{ @items [(_1 1) (_2 2) (_3 3) (_4 4) (_5 5) (_6 6) (_7 7) (_8 8)] $($args)+ }
+    };
+}
+
+#[cfg(feature = "in-list-16")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! in_list_items {
+    ($($args:tt)+) => {
+        $crate::in_list_sql!{ @items [
+            (_1 1) (_2 2) (_3 3) (_4 4) (_5 5) (_6 6) (_7 7) (_8 8)
+            (_9 9) (_10 10) (_11 11) (_12 12) (_13 13) (_14 14) (_15 15) (_16 16)
+        ] $($args)+ }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! in_list_literal {
+    ( [$($acc:tt)*] $list:ident $names:tt $items:tt ) => {
+        ::std::concat!( $($acc)* )
+    };
+    ( [$($acc:tt)*] $list:ident $names:tt $items:tt $text:literal $($tail:tt)* ) => {
+        $crate::in_list_literal!{ [$($acc)* $text ,] $list $names $items $($tail)* }
+    };
+    ( [$($acc:tt)*] $list:ident $names:tt $items:tt : $param:ident $($tail:tt)* ) => {
+        $crate::in_list_literal!{ [$($acc)* $crate::placeholder!($param in $names) ,] $list $names $items $($tail)* }
+    };
+    ( [$($acc:tt)*] $list:ident $names:tt $items:tt # $param:ident $($tail:tt)* ) => {
+        $crate::in_list_literal!{ [$($acc)* $crate::list_placeholders!($param $names $items) ,] $list $names $items $($tail)* }
+    };
+}
+
+#[cfg(not(feature = "named-params"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! list_placeholders {
+    ($param:ident $names:tt []) => {
+        "NULL"
+    };
+    ($param:ident $names:tt [($first:ident $first_num:tt) $(($item:ident $num:tt))*]) => {
+        ::std::concat!(
+            '?', $crate::index_of!($first in $names + 1)
+            $(, ", ?", $crate::index_of!($item in $names + 1))*
+        )
+    };
+}
+
+#[cfg(feature = "named-params")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! list_placeholders {
+    ($param:ident $names:tt []) => {
+        "NULL"
+    };
+    ($param:ident $names:tt [($first:ident $first_num:tt) $(($item:ident $num:tt))*]) => {
+        ::std::concat!(
+            ':', ::std::stringify!($param), "__", ::std::stringify!($first_num)
+            $(, ", :", ::std::stringify!($param), "__", ::std::stringify!($num))*
+        )
+    };
+}
+
+#[cfg(not(feature = "named-params"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! list_args {
+    ($args:ident) => {};
+    ($args:ident # $param:ident $($tail:tt)*) => {
+        for arg in $param.into_iter() {
+            $args.push(arg);
+        }
+        $crate::list_args!($args $($tail)*);
+    };
+    ($args:ident $pv:tt $param:ident $($tail:tt)*) => {
+        $crate::list_args!($args $($tail)*);
+    };
+}
+
+#[cfg(feature = "named-params")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! list_args {
+    ($($any:tt)*) => {};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! sql_len {
+    () => { 0 };
+    ($text:literal $($tail:tt)*) => { $text.len() + $crate::sql_len!($($tail)*) };
+    (: $head:ident $($tail:tt)*) => { 3 + $crate::sql_len!($($tail)*) };
+    (# $head:ident $($tail:tt)*) => { $head.len() * 5 + $crate::sql_len!($($tail)*) };
+}
+
+#[cfg(not(feature = "named-params"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! dynamic_sql {
+    ($($args:tt)+) => {
+        $crate::build_sql!(numbered $($args)+)
+    };
+}
+
+#[cfg(feature = "named-params")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! dynamic_sql {
+    ($($args:tt)+) => {
+        $crate::build_sql!(named $($args)+)
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! build_sql {
+    ($mode:ident $stmt:ident $args:ident $i:ident) => {};
+    ($mode:ident $stmt:ident $args:ident $i:ident $text:literal $($tail:tt)*) => {
+        $stmt.push_str($text);
+        $crate::build_sql!($mode $stmt $args $i $($tail)*);
+    };
+    ($mode:ident $stmt:ident $args:ident $i:ident : $param:ident $($tail:tt)*) => {
+        match $param {
+            $crate::DynParam::Bind(pos) => {
+                $crate::push_placeholder!($mode $stmt $args $param pos);
+            },
+            $crate::DynParam::Ident(ident) => {
+                $stmt.push_str(ident);
+            }
+        }
+        $crate::build_sql!($mode $stmt $args $i $($tail)*);
+    };
+    ($mode:ident $stmt:ident $args:ident $i:ident # $param:ident $($tail:tt)*) => {
+        let mut item = 0;
+        for arg in $param.into_iter() {
+            $args.push(arg);
+            $i += 1;
+            item += 1;
+            if item > 1 {
+                $stmt.push_str(", ");
+            }
+            $crate::push_list_placeholder!($mode $stmt $args $param $i item);
+        }
+        if item == 0 {
             $stmt.push_str("NULL");
         }
-        $crate::dynamic_sql!($stmt $args $i $($tail)*);
+        $crate::build_sql!($mode $stmt $args $i $($tail)*);
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! push_placeholder {
+    (numbered $stmt:ident $args:ident $param:ident $pos:ident) => {
+        $stmt.push_str(&::std::format!("?{}", $pos));
+    };
+    (named $stmt:ident $args:ident $param:ident $pos:ident) => {
+        $stmt.push_str(::std::concat!(':', ::std::stringify!($param)));
+    };
+    (literal $stmt:ident $args:ident $param:ident $pos:ident) => {
+        $crate::push_literal!($stmt $args[$pos - 1]);
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! push_list_placeholder {
+    (numbered $stmt:ident $args:ident $param:ident $i:ident $item:ident) => {
+        $stmt.push_str(&::std::format!("?{}", $i));
+    };
+    (named $stmt:ident $args:ident $param:ident $i:ident $item:ident) => {
+        $stmt.push_str(&::std::format!(::std::concat!(':', ::std::stringify!($param), "__{}"), $item));
+    };
+    (literal $stmt:ident $args:ident $param:ident $i:ident $item:ident) => {
+        $crate::push_literal!($stmt $args[$i - 1]);
+    };
+}
+
+#[cfg(feature = "render")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! push_literal {
+    ($stmt:ident $arg:expr) => {
+        let output = ::rusqlite::ToSql::to_sql($arg)?;
+        #[allow(unreachable_patterns)]
+        let value = match &output {
+            ::rusqlite::types::ToSqlOutput::Borrowed(value) => *value,
+            ::rusqlite::types::ToSqlOutput::Owned(value) => ::rusqlite::types::ValueRef::from(value),
+            _ => return Err(::rusqlite::Error::ToSqlConversionFailure(::std::boxed::Box::new($crate::render::Unrenderable))),
+        };
+        match value {
+            ::rusqlite::types::ValueRef::Null => $crate::render::push_null(&mut $stmt),
+            ::rusqlite::types::ValueRef::Integer(val) => $crate::render::push_integer(&mut $stmt, val),
+            ::rusqlite::types::ValueRef::Real(val) => $crate::render::push_real(&mut $stmt, val),
+            ::rusqlite::types::ValueRef::Text(text) => $crate::render::push_text(&mut $stmt, text),
+            ::rusqlite::types::ValueRef::Blob(blob) => $crate::render::push_blob(&mut $stmt, blob),
+        }
+    };
+}
+
+#[cfg(feature = "render")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! decl_render_method {
+    ( $name:ident $doc:literal ($($params:tt)*) ) => {
+        $crate::paste::paste!{
+            $crate::decl_method!{ render [<render_ $name>] $doc () () $($params)* }
+        }
+    };
+}
+
+#[cfg(not(feature = "render"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! decl_render_method {
+    ($($any:tt)*) => {
+        ::std::compile_error!("`render` methods require the `render` feature of include-sqlite-sql");
+    };
+}
+
+#[cfg(feature = "render")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_render_method {
+    ( $name:ident ($($params:tt)*) $($text:tt)+ ) => {
+        $crate::paste::paste!{
+            $crate::impl_method!{ render [<render_ $name>] () () {} ($($params)*) => () $($text)+ }
+        }
+    };
+}
+
+#[cfg(not(feature = "render"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_render_method {
+    ($($any:tt)*) => {};
+}
+
+#[cfg(not(feature = "named-params"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! bind_dyn_args {
+    ($stmt:ident $args:ident $($pv:tt $param:ident)+) => {
+        for (idx, arg) in $args.into_iter().enumerate() {
+            $stmt.raw_bind_parameter(idx + 1, arg)?;
+        }
+    };
+}
+
+#[cfg(feature = "named-params")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! bind_dyn_args {
+    ($stmt:ident $args:ident) => {};
+    ($stmt:ident $args:ident : $param:ident $($tail:tt)*) => {
+        if let $crate::DynParam::Bind(pos) = $param {
+            $stmt.raw_bind_parameter($crate::param_index!($stmt ::std::concat!(':', ::std::stringify!($param))), $args[pos - 1])?;
+        }
+        $crate::bind_dyn_args!($stmt $args $($tail)*);
+    };
+    ($stmt:ident $args:ident % $param:ident $($tail:tt)*) => {
+        $crate::bind_dyn_args!($stmt $args $($tail)*);
+    };
+    ($stmt:ident $args:ident # $param:ident $($tail:tt)*) => {
+        for (item, arg) in $param.into_iter().enumerate() {
+            let name = ::std::format!(::std::concat!(':', ::std::stringify!($param), "__{}"), item + 1);
+            $stmt.raw_bind_parameter($crate::param_index!($stmt &name), arg)?;
+        }
+        $crate::bind_dyn_args!($stmt $args $($tail)*);
     };
 }
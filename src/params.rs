@@ -0,0 +1,93 @@
+//! Binding of statement parameters from serialized values
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Errors that might be reported while parameters are extracted from serialized values.
+#[derive(Debug)]
+pub enum Error {
+    /// Parameters could not be serialized
+    Serialize(serde_json::Error),
+    /// Parameters were not serialized as a map
+    NotAMap,
+    /// Serialized parameters do not have the named parameter
+    Missing(&'static str),
+    /// The named IN-list parameter was not serialized as a sequence
+    NotAList(&'static str),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Serialize(err) => err.fmt(f),
+            Error::NotAMap => f.write_str("parameters are not serialized as a map"),
+            Error::Missing(name) => write!(f, "parameter `{}` is missing", name),
+            Error::NotAList(name) => write!(f, "IN-list parameter `{}` is not serialized as a sequence", name),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Serialize(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// SQL value that a serialized parameter is converted into.
+///
+/// This is expected to be `rusqlite::types::Value`.
+pub trait SqlValue: From<Option<i64>> + From<bool> + From<i64> + From<f64> + From<String> {}
+
+impl<V> SqlValue for V where V: From<Option<i64>> + From<bool> + From<i64> + From<f64> + From<String> {}
+
+/// Serialized statement parameters.
+pub struct Params(Map<String, Value>);
+
+impl Params {
+    /// Serializes parameters into a map of values.
+    pub fn new<T: Serialize + ?Sized>(params: &T) -> Result<Self, Error> {
+        match serde_json::to_value(params) {
+            Ok(Value::Object(map)) => Ok(Self(map)),
+            Ok(_) => Err(Error::NotAMap),
+            Err(err) => Err(Error::Serialize(err)),
+        }
+    }
+
+    /// Returns the value of the named parameter.
+    pub fn value<V: SqlValue>(&self, name: &'static str) -> Result<V, Error> {
+        self.0.get(name).map(to_sql_value).ok_or(Error::Missing(name))
+    }
+
+    /// Returns the value of the named parameter or `None` if it is missing or was serialized as `null`.
+    pub fn get<V: SqlValue>(&self, name: &'static str) -> Option<V> {
+        self.0.get(name).filter(|value| !value.is_null()).map(to_sql_value)
+    }
+
+    /// Returns values of the named IN-list parameter.
+    pub fn list<V: SqlValue>(&self, name: &'static str) -> Result<Vec<V>, Error> {
+        match self.0.get(name) {
+            Some(Value::Array(items)) => Ok(items.iter().map(to_sql_value).collect()),
+            Some(_) => Err(Error::NotAList(name)),
+            None => Err(Error::Missing(name)),
+        }
+    }
+}
+
+/// Converts serialized value into an SQL value.
+///
+/// Nested sequences and maps are converted into JSON text.
+fn to_sql_value<V: SqlValue>(value: &Value) -> V {
+    match value {
+        Value::Null => V::from(None),
+        Value::Bool(val) => V::from(*val),
+        Value::Number(num) => match num.as_i64() {
+            Some(val) => V::from(val),
+            None => V::from(num.as_f64().unwrap_or(f64::NAN)),
+        },
+        Value::String(text) => V::from(text.clone()),
+        _ => V::from(value.to_string()),
+    }
+}
@@ -0,0 +1,49 @@
+//! Rendering of bound values as SQLite literals
+
+use std::fmt::Write;
+
+/// Error that is reported when a bound value has no SQL literal representation.
+#[derive(Debug)]
+pub struct Unrenderable;
+
+impl std::fmt::Display for Unrenderable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("value cannot be rendered as an SQL literal")
+    }
+}
+
+impl std::error::Error for Unrenderable {}
+
+pub fn push_null(sql: &mut String) {
+    sql.push_str("NULL");
+}
+
+pub fn push_integer(sql: &mut String, val: i64) {
+    let _ = write!(sql, "{}", val);
+}
+
+/// Appends a REAL literal. SQLite has no literals for NaN, which it stores as NULL,
+/// and infinities, which it reads from out of range literals.
+pub fn push_real(sql: &mut String, val: f64) {
+    if val.is_nan() {
+        sql.push_str("NULL");
+    } else if val.is_infinite() {
+        sql.push_str(if val > 0.0 { "9e999" } else { "-9e999" });
+    } else {
+        let _ = write!(sql, "{:?}", val);
+    }
+}
+
+pub fn push_text(sql: &mut String, text: &[u8]) {
+    sql.push('\'');
+    sql.push_str(&String::from_utf8_lossy(text).replace('\'', "''"));
+    sql.push('\'');
+}
+
+pub fn push_blob(sql: &mut String, blob: &[u8]) {
+    sql.push_str("X'");
+    for byte in blob {
+        let _ = write!(sql, "{:02X}", byte);
+    }
+    sql.push('\'');
+}
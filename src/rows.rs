@@ -0,0 +1,228 @@
+//! Deserialization of result rows
+
+use serde::de::value::{BorrowedStrDeserializer, SeqDeserializer};
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, Visitor};
+use serde::forward_to_deserialize_any;
+use serde_json::de::StrRead;
+use std::fmt;
+use std::iter::Enumerate;
+
+/// Value of a result column.
+pub enum Column<'de> {
+    Null,
+    Integer(i64),
+    Real(f64),
+    Text(&'de [u8]),
+    Blob(&'de [u8]),
+}
+
+/// Error that is reported when a row cannot be deserialized.
+#[derive(Debug)]
+pub enum Error {
+    /// Value of the column with the specified index cannot be deserialized
+    Column(usize, serde_json::Error),
+    /// Row has no column for the specified field
+    MissingColumn(&'static str),
+    /// Row as a whole cannot be deserialized
+    Row(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Column(_, err) => err.fmt(f),
+            Error::MissingColumn(name) => write!(f, "missing column `{}`", name),
+            Error::Row(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Column(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Row(msg.to_string())
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Error::MissingColumn(field)
+    }
+}
+
+/// Deserializes a row from its columns as if it was a map of column names to column values.
+///
+/// Text that is deserialized into a struct, a map, or a sequence is parsed as JSON. Text that is deserialized
+/// into an enum is taken for a variant name.
+pub fn deserialize<'de, T, I>(columns: I) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+    I: IntoIterator<Item = (&'de str, Column<'de>)>,
+{
+    T::deserialize(Row { columns: columns.into_iter() })
+}
+
+struct Row<I> {
+    columns: I,
+}
+
+impl<'de, I> de::Deserializer<'de> for Row<I>
+where
+    I: Iterator<Item = (&'de str, Column<'de>)>,
+{
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_map(Columns { columns: self.columns.enumerate(), value: None })
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct Columns<'de, I> {
+    columns: Enumerate<I>,
+    value: Option<(usize, Column<'de>)>,
+}
+
+impl<'de, I> MapAccess<'de> for Columns<'de, I>
+where
+    I: Iterator<Item = (&'de str, Column<'de>)>,
+{
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Error> {
+        match self.columns.next() {
+            Some((idx, (name, value))) => {
+                self.value = Some((idx, value));
+                seed.deserialize(BorrowedStrDeserializer::<serde_json::Error>::new(name))
+                    .map(Some)
+                    .map_err(|err| Error::Column(idx, err))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let (idx, value) = self.value.take().ok_or_else(|| Error::Row(String::from("column value was requested before its name")))?;
+        seed.deserialize(value).map_err(|err| Error::Column(idx, err))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.columns.size_hint().1
+    }
+}
+
+impl<'de> Column<'de> {
+    fn text(text: &'de [u8]) -> Result<&'de str, serde_json::Error> {
+        std::str::from_utf8(text).map_err(de::Error::custom)
+    }
+
+    fn json<V, F>(text: &'de [u8], parse: F) -> Result<V, serde_json::Error>
+    where
+        F: FnOnce(&mut serde_json::Deserializer<StrRead<'de>>) -> Result<V, serde_json::Error>,
+    {
+        let mut json = serde_json::Deserializer::from_str(Self::text(text)?);
+        let value = parse(&mut json)?;
+        json.end()?;
+        Ok(value)
+    }
+}
+
+impl<'de> IntoDeserializer<'de, serde_json::Error> for Column<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+macro_rules! deserialize_json {
+    ($($method:ident ($($arg:ident : $arg_type:ty),*))+) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $($arg: $arg_type, )* visitor: V) -> Result<V::Value, serde_json::Error> {
+                match self {
+                    Column::Text(text) => Column::json(text, |json| de::Deserializer::$method(json, $($arg, )* visitor)),
+                    _ => self.deserialize_any(visitor),
+                }
+            }
+        )+
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Column<'de> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, serde_json::Error> {
+        match self {
+            Column::Null => visitor.visit_unit(),
+            Column::Integer(val) => visitor.visit_i64(val),
+            Column::Real(val) => visitor.visit_f64(val),
+            Column::Text(text) => visitor.visit_borrowed_str(Self::text(text)?),
+            Column::Blob(blob) => {
+                let mut bytes = SeqDeserializer::new(blob.iter().copied());
+                let value = visitor.visit_seq(&mut bytes)?;
+                bytes.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, serde_json::Error> {
+        match self {
+            Column::Integer(val) => visitor.visit_bool(val != 0),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, serde_json::Error> {
+        match self {
+            Column::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, serde_json::Error> {
+        match self {
+            Column::Text(bytes) | Column::Blob(bytes) => visitor.visit_borrowed_bytes(bytes),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, serde_json::Error> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, serde_json::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value, serde_json::Error> {
+        match self {
+            Column::Text(text) => visitor.visit_enum(BorrowedStrDeserializer::new(Self::text(text)?)),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    deserialize_json! {
+        deserialize_seq()
+        deserialize_tuple(len: usize)
+        deserialize_tuple_struct(name: &'static str, len: usize)
+        deserialize_map()
+        deserialize_struct(name: &'static str, fields: &'static [&'static str])
+    }
+
+    forward_to_deserialize_any! {
+        i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        unit unit_struct identifier ignored_any
+    }
+}
@@ -0,0 +1,57 @@
+use include_sqlite_sql::{include_sql, impl_sql, InvalidArgument};
+use rusqlite::{Result, Connection, Error};
+
+include_sql!("/tests/init.sql");
+include_sql!("/tests/checks.sql");
+
+fn invalid_argument(err: Error) -> InvalidArgument {
+    match err {
+        Error::ToSqlConversionFailure(err) => *err.downcast::<InvalidArgument>().expect("invalid argument error"),
+        _ => panic!("unexpected error: {:?}", err),
+    }
+}
+
+#[test]
+fn checks() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.create_test_table()?;
+    db.insert_test_quotes()?;
+
+    let mut row_num = 0;
+    db.get_quotes_page(5, |_| { row_num += 1; Ok(()) })?;
+    assert_eq!(row_num, 5);
+
+    let err = invalid_argument(db.get_quotes_page(6, |_| Ok(())).unwrap_err());
+    assert_eq!(err.method, "get_quotes_page");
+    assert_eq!(err.param, "limit");
+    assert_eq!(err.to_string(), "argument `limit` of `get_quotes_page` is not between 1 and 5");
+
+    let count = db.insert_new_quote("Seneca", "Luck is what happens when preparation meets opportunity.")?;
+    assert_eq!(count, 1);
+
+    let err = invalid_argument(db.insert_new_quote("", "Anonymous quote.").unwrap_err());
+    assert_eq!(err.param, "author");
+    assert_eq!(err.to_string(), "argument `author` of `insert_new_quote` length is not between 1 and 30");
+
+    let err = invalid_argument(db.insert_new_quote("Seneca", &"x".repeat(101)).unwrap_err());
+    assert_eq!(err.param, "quote");
+
+    let count : i64 = db.query_row("SELECT Count(*) FROM quotes", [], |row| row.get(0))?;
+    assert_eq!(count, 11);
+
+    Ok(())
+}
+
+#[test]
+fn unsupported_placeholders() {
+    use include_sqlite_sql::find_unsupported_placeholder as find;
+
+    assert_eq!(find("SELECT * FROM quotes WHERE id = :id"), None);
+    assert_eq!(find("SELECT * FROM quotes WHERE id = ?1"), Some(32));
+    assert_eq!(find("SELECT * FROM quotes WHERE id = ?"), Some(32));
+    assert_eq!(find("SELECT * FROM quotes WHERE id = @id"), Some(32));
+    assert_eq!(find("SELECT * FROM quotes WHERE id = $id"), Some(32));
+    assert_eq!(find("SELECT a$b FROM quotes WHERE q = '?' AND \"@a\" = [$b] -- ?\n/* @c */"), None);
+    assert_eq!(find("SELECT 1 /* ? */, ?"), Some(18));
+}
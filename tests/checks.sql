@@ -0,0 +1,13 @@
+-- name: get_quotes_page ?
+-- param: limit: range<i64,1,5> - page size
+SELECT id
+  FROM quotes
+ ORDER BY id
+ LIMIT :limit
+/
+
+-- name: insert_new_quote !
+-- param: author: len<1,30> - quote author
+-- param: quote: len<1,100> - quote text
+INSERT INTO quotes (author, quote) VALUES (:author, :quote)
+/
@@ -0,0 +1,56 @@
+use include_sqlite_sql::{include_sql, impl_sql};
+use rusqlite::{Result, Connection};
+
+include_sql!("/tests/init.sql");
+include_sql!("/tests/conversions.sql");
+
+struct QuoteId(u8);
+
+impl From<QuoteId> for i64 {
+    fn from(id: QuoteId) -> Self {
+        id.0 as i64
+    }
+}
+
+#[test]
+fn conversions() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.create_test_table()?;
+    db.insert_test_quotes()?;
+
+    let author = String::from("Laozi");
+    for arg in [author.as_str(), "Laozi"] {
+        let mut row_num = 0;
+        db.get_quotes_by_author(arg, |row| {
+            row_num += 1;
+            let quote : &str = row.get_ref(0)?.as_str()?;
+            assert_eq!(quote, "The journey of a thousand miles begins with a single step.");
+            Ok(())
+        })?;
+        assert_eq!(row_num, 1);
+    }
+    let mut row_num = 0;
+    db.get_quotes_by_author(&author, |_| { row_num += 1; Ok(()) })?;
+    db.get_quotes_by_author(author.clone(), |_| { row_num += 1; Ok(()) })?;
+    assert_eq!(row_num, 2);
+
+    for id in [QuoteId(6).into(), 6i64] {
+        db.get_quote_by_id(id, |row| {
+            let author : &str = row.get_ref(0)?.as_str()?;
+            assert_eq!(author, "Albert Einstein");
+            Ok(())
+        })?;
+    }
+    db.get_quote_by_id(QuoteId(9), |row| {
+        let author : &str = row.get_ref(0)?.as_str()?;
+        assert_eq!(author, "George Orwell");
+        Ok(())
+    })?;
+
+    let mut row_num = 0;
+    db.get_quotes_by_author_and_ids(&author, &[1, 10], |_| { row_num += 1; Ok(()) })?;
+    assert_eq!(row_num, 1);
+
+    Ok(())
+}
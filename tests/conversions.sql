@@ -0,0 +1,22 @@
+-- name: get_quotes_by_author ?
+-- param: author: as_ref<str> - author name
+SELECT quote
+  FROM quotes
+ WHERE author = :author
+/
+
+-- name: get_quote_by_id ?
+-- param: id: into<i64> - quote ID
+SELECT author, quote
+  FROM quotes
+ WHERE id = :id
+/
+
+-- name: get_quotes_by_author_and_ids ?
+-- param: author: as_ref<str> - author name
+-- param: ids: i64 - quote IDs
+SELECT quote
+  FROM quotes
+ WHERE author = :author
+   AND id IN (:ids)
+/
@@ -0,0 +1,39 @@
+use include_sqlite_sql::{include_sql, impl_sql};
+use rusqlite::{Result, Connection};
+
+include_sql!("/tests/init.sql");
+include_sql!("/tests/defaults.sql");
+
+#[test]
+fn defaults() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.create_test_table()?;
+    db.insert_test_quotes()?;
+
+    let mut authors = Vec::new();
+    db.get_quotes_page(None, None, |row| {
+        let author : String = row.get(0)?;
+        authors.push(author);
+        Ok(())
+    })?;
+    assert_eq!(authors, ["John Keats", "Thomas Carlyle", "Theodore Roosevelt"]);
+
+    let mut authors = Vec::new();
+    db.get_quotes_page(Some(2), Some(8), |row| {
+        let author : String = row.get(0)?;
+        authors.push(author);
+        Ok(())
+    })?;
+    assert_eq!(authors, ["George Orwell", "Laozi"]);
+
+    let mut authors = Vec::new();
+    db.get_authors_page(&[1, 5, 10], None, |row| {
+        let author : String = row.get(0)?;
+        authors.push(author);
+        Ok(())
+    })?;
+    assert_eq!(authors, ["John Keats", "Confucius"]);
+
+    Ok(())
+}
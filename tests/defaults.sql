@@ -0,0 +1,18 @@
+-- name: get_quotes_page ?
+-- param: limit: default<i32,3> - page size
+-- param: offset: default<i32,0> - number of quotes to skip
+SELECT author
+  FROM quotes
+ ORDER BY id
+ LIMIT :limit OFFSET :offset
+/
+
+-- name: get_authors_page ?
+-- param: ids: i32 - quote IDs
+-- param: limit: default<i32,2> - page size
+SELECT author
+  FROM quotes
+ WHERE id IN (:ids)
+ ORDER BY id
+ LIMIT :limit
+/
@@ -0,0 +1,95 @@
+use include_sqlite_sql::include_sql;
+use rusqlite::{Result, Connection, Error};
+use rusqlite::types::Type;
+use serde::Deserialize;
+
+macro_rules! impl_sql {
+    ($($t:tt)+) => { include_sqlite_sql::impl_sql_extra!{ [deserialized] ; $($t)+ } };
+}
+
+include_sql!("/tests/init.sql");
+include_sql!("/tests/serde.sql");
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Quote {
+    author: String,
+    quote: String,
+}
+
+#[derive(Deserialize)]
+struct NewQuote {
+    id: i64,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Tags {
+    tags: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct TaggedQuote {
+    author: Tags,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+enum Author {
+    Seneca,
+    #[serde(rename = "Laozi")]
+    LaoTzu,
+}
+
+#[derive(Deserialize)]
+struct AuthorQuote {
+    author: Author,
+}
+
+#[derive(Deserialize)]
+struct NumberedQuote {
+    #[allow(dead_code)]
+    author: i64,
+}
+
+#[test]
+fn deserialize() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.create_test_table()?;
+    db.insert_test_quotes()?;
+
+    let quotes : Vec<Quote> = db.get_quotes_by_ids_deserialized(&[3, 5], None)?;
+    assert_eq!(quotes, [
+        Quote { author: "Theodore Roosevelt".to_string(), quote: "Believe you can and you’re halfway there.".to_string() },
+        Quote { author: "Confucius".to_string(), quote: "Wherever you go, go with all your heart.".to_string() },
+    ]);
+
+    let new_quote : NewQuote = db.insert_new_quote_deserialized("Seneca", "Difficulties strengthen the mind, as labor does the body.")?;
+    assert_eq!(new_quote.id, 11);
+
+    // enum fields are deserialized from variant names
+    let quotes : Vec<AuthorQuote> = db.get_quotes_by_ids_deserialized(&[10, 11], None)?;
+    assert_eq!(quotes.iter().map(|quote| &quote.author).collect::<Vec<_>>(), [&Author::LaoTzu, &Author::Seneca]);
+
+    db.update_author(r#"{ "tags": ["mind", "labor"] }"#, 11)?;
+    let quotes : Vec<TaggedQuote> = db.get_quote_by_id_deserialized(11)?;
+    assert_eq!(quotes.len(), 1);
+    assert_eq!(quotes[0].author, Tags { tags: vec!["mind".to_string(), "labor".to_string()] });
+
+    // JSON text is parsed only when the field expects a nested value
+    let quotes : Vec<Quote> = db.get_quote_by_id_deserialized(11)?;
+    assert_eq!(quotes[0].author, r#"{ "tags": ["mind", "labor"] }"#);
+
+    // and is not parsed for enum fields either, whatever the column holds
+    let res = db.get_quote_by_id_deserialized::<AuthorQuote>(11);
+    assert!(matches!(res, Err(Error::FromSqlConversionFailure(0, Type::Text, _))));
+
+    let res = db.get_quote_by_id_deserialized::<NewQuote>(1);
+    assert!(matches!(res, Err(Error::InvalidColumnName(name)) if name == "id"));
+
+    let res = db.get_quote_by_id_deserialized::<NumberedQuote>(1);
+    assert!(matches!(res, Err(Error::FromSqlConversionFailure(0, Type::Text, _))));
+
+    let res = db.get_quote_by_id_deserialized::<String>(1);
+    assert!(matches!(res, Err(Error::SqliteFailure(..))));
+
+    Ok(())
+}
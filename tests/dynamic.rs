@@ -0,0 +1,54 @@
+use include_sqlite_sql::{include_sql, impl_sql};
+use rusqlite::{Result, Connection};
+
+include_sql!("/tests/init.sql");
+include_sql!("/tests/dynamic.sql");
+
+#[test]
+fn dynamic() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.create_test_table()?;
+    db.insert_test_quotes()?;
+
+    db.get_repeated_params(2, &[1, 2, 3], |row| {
+        let stmt = row.as_ref();
+        #[cfg(not(feature = "named-params"))] {
+            assert_eq!(stmt.column_name(0)?, "?1");
+            assert_eq!(stmt.column_name(1)?, "?1 IN (?2, ?3, ?4)");
+            assert_eq!(stmt.column_name(2)?, "?1 + 1");
+        }
+        assert_eq!(stmt.parameter_count(), 4);
+        let id : i32 = row.get(0)?;
+        let found : bool = row.get(1)?;
+        let next_id : i32 = row.get(2)?;
+        assert_eq!(id, 2);
+        assert!(found);
+        assert_eq!(next_id, 3);
+        Ok(())
+    })?;
+
+    db.get_repeated_params(2, &[], |row| {
+        #[cfg(not(feature = "named-params"))]
+        assert_eq!(row.as_ref().column_name(1)?, "?1 IN (NULL)");
+        let found : Option<bool> = row.get(1)?;
+        assert!(found.is_none());
+        Ok(())
+    })?;
+
+    // longer than any precomputed variant, so the SQL is built at run time
+    db.get_repeated_params(17, (1..=17).collect::<Vec<_>>().as_slice(), |row| {
+        assert_eq!(row.as_ref().parameter_count(), 18);
+        let found : bool = row.get(1)?;
+        assert!(found);
+        Ok(())
+    })?;
+
+    db.count_quotes_by_ids(&[1, 2, 6, 7], 30, |row| {
+        let count : i32 = row.get(0)?;
+        assert_eq!(count, 3);
+        Ok(())
+    })?;
+
+    Ok(())
+}
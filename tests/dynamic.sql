@@ -0,0 +1,15 @@
+-- name: get_repeated_params ?
+-- param: id: i32
+-- param: ids: i32
+SELECT :id, :id IN (:ids), :id + 1
+/
+
+-- name: count_quotes_by_ids ?
+-- param: ids: i32
+-- param: min_len: i32
+SELECT Count(*)
+  FROM quotes
+ WHERE id IN (:ids)
+   AND Length(quote) >= :min_len
+   AND Length(author) < :min_len
+/
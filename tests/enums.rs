@@ -0,0 +1,65 @@
+use include_sqlite_sql::{include_sql, impl_sql, sql_enum, UnknownVariant};
+use rusqlite::{Result, Connection, Error};
+
+include_sql!("/tests/enums.sql");
+
+sql_enum!{
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum LoanStatus as text {
+        Loaned   = "loaned",
+        Returned = "returned",
+        Lost     = "lost",
+    }
+}
+
+sql_enum!{
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Priority as integer {
+        Low  = 1,
+        High = 2,
+    }
+}
+
+#[test]
+fn enums() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.create_loans_table()?;
+    db.insert_loan("Gone With the Wind", LoanStatus::Returned, Priority::Low)?;
+    db.insert_loan("The Trial", LoanStatus::Lost, Priority::High)?;
+    db.insert_loan("War and Peace", LoanStatus::Loaned, Priority::High)?;
+
+    let mut rows = Vec::new();
+    db.get_loans_by_status(&[LoanStatus::Loaned, LoanStatus::Returned], |row| {
+        let title : String = row.get(0)?;
+        let status : LoanStatus = row.get(1)?;
+        let priority : Priority = row.get(2)?;
+        rows.push((title, status, priority));
+        Ok(())
+    })?;
+    assert_eq!(rows, [
+        ("Gone With the Wind".to_string(), LoanStatus::Returned, Priority::Low),
+        ("War and Peace".to_string(), LoanStatus::Loaned, Priority::High),
+    ]);
+
+    db.insert_raw_loan("Ulysses", "overdue", 3)?;
+    db.get_loan_status("Ulysses", |row| {
+        match row.get::<_, LoanStatus>(0) {
+            Err(Error::FromSqlConversionFailure(_, _, err)) => {
+                let err = err.downcast_ref::<UnknownVariant>().expect("unknown variant error");
+                assert_eq!(err.enum_name, "LoanStatus");
+                assert_eq!(err.value, "overdue");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        match row.get::<_, Priority>(1) {
+            Err(Error::FromSqlConversionFailure(_, _, err)) => {
+                assert_eq!(err.to_string(), "`3` is not a valid Priority value");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        Ok(())
+    })?;
+
+    Ok(())
+}
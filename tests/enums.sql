@@ -0,0 +1,33 @@
+-- name: create_loans_table !
+CREATE TABLE loans (
+    book_title  TEXT,
+    status      TEXT,
+    priority    INTEGER
+)
+/
+
+-- name: insert_loan !
+-- param: book_title: &str - book title
+-- param: status: LoanStatus - loan status
+-- param: priority: Priority - loan priority
+INSERT INTO loans (book_title, status, priority) VALUES (:book_title, :status, :priority)
+/
+
+-- name: insert_raw_loan !
+INSERT INTO loans (book_title, status, priority) VALUES (:book_title, :status, :priority)
+/
+
+-- name: get_loans_by_status ?
+-- param: statuses: LoanStatus - loan statuses
+SELECT book_title, status, priority
+  FROM loans
+ WHERE status IN (:statuses)
+ ORDER BY book_title
+/
+
+-- name: get_loan_status ?
+-- param: book_title: &str - book title
+SELECT status, priority
+  FROM loans
+ WHERE book_title = :book_title
+/
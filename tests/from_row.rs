@@ -0,0 +1,69 @@
+use include_sqlite_sql::{include_sql, sql_from_row};
+use rusqlite::{Result, Connection};
+
+macro_rules! impl_sql {
+    ($($t:tt)+) => { include_sqlite_sql::impl_sql_extra!{ [rows] ; $($t)+ } };
+}
+
+include_sql!("/tests/init.sql");
+include_sql!("/tests/queries.sql");
+include_sql!("/tests/returning.sql");
+include_sql!("/tests/from_row.sql");
+
+sql_from_row!{
+    #[derive(Debug, PartialEq)]
+    struct Quote {
+        author: String,
+        #[rename = "quote"]
+        text: String,
+        #[skip]
+        tags: Vec<String>,
+    }
+}
+
+sql_from_row!{
+    struct NewQuote {
+        id: i64,
+    }
+}
+
+sql_from_row!{
+    struct FirstId {
+        first_id: i64,
+    }
+}
+
+#[test]
+fn from_row() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.create_test_table()?;
+    db.insert_test_quotes()?;
+
+    let quotes : Vec<Quote> = db.get_quotes_longer_than_rows(67)?;
+    assert_eq!(quotes, [
+        Quote { author: "Vincent Van Gogh".to_string(), text: "Great things are done by a series of small things brought together.".to_string(), tags: Vec::new() },
+        Quote { author: "Albert Einstein".to_string(), text: "Life is like riding a bicycle. To keep your balance, you must keep moving.".to_string(), tags: Vec::new() },
+    ]);
+
+    let quote : Option<Quote> = db.get_quotes_longer_than_row(67)?;
+    assert_eq!(quote.map(|quote| quote.author), Some("Vincent Van Gogh".to_string()));
+
+    let quote : Option<Quote> = db.get_quotes_longer_than_row(1000)?;
+    assert!(quote.is_none());
+
+    let new_quote : NewQuote = db.insert_new_quote_row("Seneca", "We suffer more often in imagination than in reality.")?;
+    assert_eq!(new_quote.id, 11);
+
+    let quote : Option<Quote> = db.get_quote_by_id_row(new_quote.id as i32)?;
+    assert_eq!(quote.map(|quote| quote.author), Some("Seneca".to_string()));
+
+    // only the first row is retrieved
+    assert!(db.get_first_ids_rows::<FirstId>().is_err());
+    let first : Option<FirstId> = db.get_first_ids_row()?;
+    assert_eq!(first.map(|row| row.first_id), Some(1));
+    let first : Option<FirstId> = db.get_first_selected_ids_row(&[1, 2, 3])?;
+    assert_eq!(first.map(|row| row.first_id), Some(1));
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+-- name: get_first_ids ?
+-- Fails on every row after the first one
+SELECT CASE WHEN id = 1 THEN id ELSE abs(id - id - 9223372036854775807 - 1) END AS first_id
+  FROM quotes
+/
+
+-- name: get_first_selected_ids ?
+-- Fails on every row after the first one
+-- param: ids: i32 - quote IDs
+SELECT CASE WHEN id = 1 THEN id ELSE abs(id - id - 9223372036854775807 - 1) END AS first_id
+  FROM quotes
+ WHERE id IN (:ids)
+/
@@ -0,0 +1,56 @@
+macro_rules! impl_sql {
+    ($($t:tt)+) => { include_sqlite_sql::impl_sql_tests!{ "/tests/schema.sql" ; $($t)+ } };
+}
+
+mod queries {
+    include_sqlite_sql::include_sql!("/tests/queries.sql");
+}
+
+mod updates {
+    include_sqlite_sql::include_sql!("/tests/updates.sql");
+}
+
+mod returning {
+    include_sqlite_sql::include_sql!("/tests/returning.sql");
+}
+
+mod dynamic {
+    include_sqlite_sql::include_sql!("/tests/dynamic.sql");
+}
+
+mod checks {
+    include_sqlite_sql::include_sql!("/tests/checks.sql");
+}
+
+mod conversions {
+    include_sqlite_sql::include_sql!("/tests/conversions.sql");
+}
+
+mod optional {
+    include_sqlite_sql::include_sql!("/tests/optional.sql");
+}
+
+mod sensitive {
+    include_sqlite_sql::include_sql!("/tests/sensitive.sql");
+}
+
+mod idents {
+    include_sqlite_sql::sql_ident!{
+        #[derive(Default)]
+        pub enum QuoteOrder {
+            #[default]
+            Author = author,
+            Quote  = "quote",
+        }
+    }
+
+    include_sqlite_sql::sql_ident!{
+        #[derive(Default)]
+        pub enum Table {
+            #[default]
+            Quotes = quotes,
+        }
+    }
+
+    include_sqlite_sql::include_sql!("/tests/idents.sql");
+}
@@ -0,0 +1,85 @@
+use include_sqlite_sql::{include_sql, impl_sql, sql_ident};
+use rusqlite::{Result, Connection, config::DbConfig};
+
+include_sql!("/tests/init.sql");
+include_sql!("/tests/idents.sql");
+
+sql_ident!{
+    enum QuoteOrder {
+        Author = author,
+        Quote  = "quote",
+        Order  = order,
+    }
+}
+
+sql_ident!{
+    enum Table {
+        Quotes = quotes,
+    }
+}
+
+#[test]
+fn idents() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.create_test_table()?;
+    db.insert_test_quotes()?;
+    db.execute_batch(r#"ALTER TABLE quotes ADD COLUMN "order" INTEGER; UPDATE quotes SET "order" = 11 - id"#)?;
+
+    let mut authors = Vec::new();
+    db.get_quotes_ordered_by(QuoteOrder::Author, 3, |row| {
+        let author : String = row.get(0)?;
+        authors.push(author);
+        Ok(())
+    })?;
+    assert_eq!(authors, ["John Keats", "Theodore Roosevelt", "Thomas Carlyle"]);
+
+    let mut authors = Vec::new();
+    db.get_quotes_ordered_by(QuoteOrder::Quote, 3, |row| {
+        let author : String = row.get(0)?;
+        authors.push(author);
+        Ok(())
+    })?;
+    assert_eq!(authors, ["Theodore Roosevelt", "John Keats", "Thomas Carlyle"]);
+
+    let mut authors = Vec::new();
+    db.get_selected_quotes_ordered_by(&[9, 5, 6], QuoteOrder::Author, |row| {
+        let author : String = row.get(0)?;
+        authors.push(author);
+        Ok(())
+    })?;
+    assert_eq!(authors, ["Albert Einstein", "Confucius", "George Orwell"]);
+
+    let mut authors = Vec::new();
+    db.get_quotes_ordered_by(QuoteOrder::Order, 3, |row| {
+        let author : String = row.get(0)?;
+        authors.push(author);
+        Ok(())
+    })?;
+    assert_eq!(authors, ["Theodore Roosevelt", "Thomas Carlyle", "John Keats"]);
+
+    let mut count = 0;
+    db.count_rows(Table::Quotes, |row| { count = row.get(0)?; Ok(()) })?;
+    assert_eq!(count, 10);
+
+    Ok(())
+}
+
+#[test]
+fn check_all() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+    // otherwise SQLite takes a double-quoted name of a missing column for a string literal
+    db.set_db_config(DbConfig::SQLITE_DBCONFIG_DQS_DML, false)?;
+
+    // every identifier is spliced into the checked statements
+    db.execute_batch(r#"CREATE TABLE quotes (id INTEGER PRIMARY KEY, author TEXT, "order" INTEGER)"#)?;
+    let errors = IdentsSql::check_all(&db).unwrap_err();
+    let names : Vec<_> = errors.iter().map(|(name, _)| *name).collect();
+    assert_eq!(names, ["get_quotes_ordered_by", "get_selected_quotes_ordered_by", "get_ids_ordered_by"]);
+    assert!(errors[2].1.to_string().contains("no such column: quote"));
+
+    db.execute_batch("ALTER TABLE quotes ADD COLUMN quote TEXT")?;
+    assert!(IdentsSql::check_all(&db).is_ok());
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+-- name: get_quotes_ordered_by ?
+-- param: order_by: ident<QuoteOrder> - sort column
+-- param: max_id: i32 - max quote ID
+SELECT author, quote
+  FROM quotes
+ WHERE id <= :max_id
+ ORDER BY :order_by
+/
+
+-- name: get_selected_quotes_ordered_by ?
+-- param: ids: i32 - quote IDs
+-- param: order_by: ident<QuoteOrder> - sort column
+SELECT author, quote
+  FROM quotes
+ WHERE id IN (:ids)
+ ORDER BY :order_by
+/
+
+-- name: count_rows ?
+-- param: table: ident<Table> - table name
+SELECT Count(*) FROM :table
+/
+
+-- name: get_ids_ordered_by ?
+-- param: order_by: ident<QuoteOrder> - sort column
+SELECT id FROM quotes ORDER BY :order_by
+/
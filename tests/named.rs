@@ -0,0 +1,34 @@
+use include_sqlite_sql::{include_sql, impl_sql};
+use rusqlite::{Result, Connection};
+
+include_sql!("/tests/named.sql");
+
+#[test]
+fn named() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.get_named_params("Laozi", 42, |row| {
+        let stmt = row.as_ref();
+        assert_eq!(stmt.column_name(0)?, ":author");
+        assert_eq!(stmt.column_name(1)?, ":min_len");
+        assert_eq!(stmt.column_name(2)?, ":author");
+        let author : &str = row.get_ref(0)?.as_str()?;
+        let min_len : i32 = row.get(1)?;
+        assert_eq!(author, "Laozi");
+        assert_eq!(min_len, 42);
+        Ok(())
+    })?;
+
+    db.get_named_list_params(2, &[1, 2, 3], |row| {
+        let stmt = row.as_ref();
+        assert_eq!(stmt.column_name(0)?, ":id");
+        assert_eq!(stmt.column_name(1)?, ":id IN (:ids__1, :ids__2, :ids__3)");
+        let id : i32 = row.get(0)?;
+        let found : bool = row.get(1)?;
+        assert_eq!(id, 2);
+        assert!(found);
+        Ok(())
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,11 @@
+-- name: get_named_params ?
+-- param: author: &str
+-- param: min_len: i32
+SELECT :author, :min_len, :author
+/
+
+-- name: get_named_list_params ?
+-- param: id: i32
+-- param: ids: i32
+SELECT :id, :id IN (:ids)
+/
@@ -0,0 +1,52 @@
+use include_sqlite_sql::{include_sql, impl_sql};
+use rusqlite::{Result, Connection};
+
+include_sql!("/tests/init.sql");
+include_sql!("/tests/optional.sql");
+
+#[test]
+fn optional() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.create_test_table()?;
+    db.insert_test_quotes()?;
+
+    let mut row_num = 0;
+    db.search_quotes(None, None, |_| {
+        row_num += 1;
+        Ok(())
+    })?;
+    assert_eq!(row_num, 10);
+
+    let mut row_num = 0;
+    db.search_quotes(Some("George Orwell"), None, |row| {
+        let author : &str = row.get_ref(0)?.as_str()?;
+        row_num += 1;
+        assert_eq!(author, "George Orwell");
+        Ok(())
+    })?;
+    assert_eq!(row_num, 1);
+
+    let mut row_num = 0;
+    db.search_quotes(None, Some(66), |row| {
+        let author : &str = row.get_ref(0)?.as_str()?;
+        row_num += 1;
+        match row_num {
+            1 => assert_eq!(author, "Albert Einstein"),
+            2 => assert_eq!(author, "Vincent Van Gogh"),
+            3 => assert_eq!(author, "George Orwell"),
+            _ => panic!("three rows were expected")
+        }
+        Ok(())
+    })?;
+    assert_eq!(row_num, 3);
+
+    let mut row_num = 0;
+    db.search_quotes(Some("George Orwell"), Some(70), |_| {
+        row_num += 1;
+        Ok(())
+    })?;
+    assert_eq!(row_num, 0);
+
+    Ok(())
+}
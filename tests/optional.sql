@@ -0,0 +1,9 @@
+-- name: search_quotes ?
+-- param: author: Option<&str> - author name filter
+-- param: min_len: Option<i32> - min quote length filter
+SELECT author, quote
+  FROM quotes
+ WHERE (:author IS NULL OR author = :author)
+   AND (:min_len IS NULL OR Length(quote) >= :min_len)
+ ORDER BY id
+/
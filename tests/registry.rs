@@ -0,0 +1,86 @@
+use include_sqlite_sql::{include_sql, impl_sql, QueryKind};
+use rusqlite::{Result, Connection};
+
+include_sql!("/tests/queries.sql");
+include_sql!("/tests/dynamic.sql");
+
+#[test]
+fn check_all() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    let errors = QueriesSql::check_all(&db).unwrap_err();
+    let names : Vec<_> = errors.iter().map(|(name, _)| *name).collect();
+    assert_eq!(names, ["count_quotes", "get_quotes_longer_than", "get_quotes_within_range"]);
+    assert!(errors[0].1.to_string().contains("no such table: quotes"));
+
+    db.execute_batch("CREATE TABLE quotes (id INTEGER PRIMARY KEY, author TEXT)")?;
+    let errors = QueriesSql::check_all(&db).unwrap_err();
+    let names : Vec<_> = errors.iter().map(|(name, _)| *name).collect();
+    assert_eq!(names, ["get_quotes_longer_than", "get_quotes_within_range"]);
+    let errors = DynamicSql::check_all(&db).unwrap_err();
+    let names : Vec<_> = errors.iter().map(|(name, _)| *name).collect();
+    assert_eq!(names, ["count_quotes_by_ids"]);
+
+    db.execute_batch("ALTER TABLE quotes ADD COLUMN quote TEXT")?;
+    assert!(QueriesSql::check_all(&db).is_ok());
+    assert!(DynamicSql::check_all(&db).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn registry() -> Result<()> {
+    let queries = <Connection as QueriesSql>::QUERIES;
+    assert_eq!(queries.len(), 3);
+
+    let names : Vec<_> = queries.iter().map(|query| query.name).collect();
+    assert_eq!(names, ["count_quotes", "get_quotes_longer_than", "get_quotes_within_range"]);
+    assert!(queries.iter().all(|query| query.kind == QueryKind::Select));
+
+    let query = &queries[0];
+    assert_eq!(query.sql, "SELECT Count(*) FROM quotes");
+    assert!(query.params.is_empty());
+
+    let query = &queries[1];
+    assert_eq!(query.sql, "SELECT author, quote\n  FROM quotes\n WHERE Length(quote) >= :min_len\n ORDER BY Length(quote)");
+    assert_eq!(query.params.len(), 1);
+    assert_eq!(query.params[0].name, "min_len");
+    assert_eq!(query.params[0].ptype, "i32");
+    assert!(!query.params[0].list);
+    assert!(query.doc.contains("min quote len"));
+
+    let query = &queries[2];
+    let params : Vec<_> = query.params.iter().map(|param| (param.name, param.ptype)).collect();
+    assert_eq!(params, [("min_len", "_"), ("max_length", "_")]);
+
+    let queries = <Connection as DynamicSql>::QUERIES;
+    let query = &queries[0];
+    assert_eq!(query.sql, "SELECT :id, :id IN (:ids), :id + 1");
+    assert!(query.params[1].list);
+    assert_eq!(query.params[1].ptype, "i32");
+
+    // the registry SQL can be prepared as is
+    let db = Connection::open_in_memory()?;
+    let stmt = db.prepare(query.sql)?;
+    assert_eq!(stmt.parameter_count(), 2);
+
+    assert_eq!(<Connection as QueriesSql>::COUNT_QUOTES_SQL, "SELECT Count(*) FROM quotes");
+    #[cfg(not(feature = "named-params"))]
+    assert_eq!(<Connection as QueriesSql>::GET_QUOTES_WITHIN_RANGE_SQL, "SELECT author, quote\n  FROM quotes\n WHERE Length(quote) BETWEEN ?1 AND ?2\n ORDER BY Length(quote)");
+    #[cfg(feature = "named-params")]
+    assert_eq!(<Connection as QueriesSql>::GET_QUOTES_WITHIN_RANGE_SQL, "SELECT author, quote\n  FROM quotes\n WHERE Length(quote) BETWEEN :min_len AND :max_length\n ORDER BY Length(quote)");
+    assert_eq!(<Connection as DynamicSql>::GET_REPEATED_PARAMS_SQL, "SELECT :id, :id IN (:ids), :id + 1");
+    let dynamic = [
+        <Connection as QueriesSql>::COUNT_QUOTES_DYNAMIC,
+        <Connection as QueriesSql>::GET_QUOTES_WITHIN_RANGE_DYNAMIC,
+        <Connection as DynamicSql>::GET_REPEATED_PARAMS_DYNAMIC,
+    ];
+    assert_eq!(dynamic, [false, false, true]);
+
+    // the same SQL can be used with plain rusqlite calls
+    db.execute_batch("CREATE TABLE quotes (id INTEGER PRIMARY KEY, author TEXT, quote TEXT)")?;
+    let count : i64 = db.query_row(<Connection as QueriesSql>::COUNT_QUOTES_SQL, [], |row| row.get(0))?;
+    assert_eq!(count, 0);
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use include_sqlite_sql::include_sql;
+use rusqlite::{Result, Connection};
+
+macro_rules! impl_sql {
+    ($($t:tt)+) => { include_sqlite_sql::impl_sql_extra!{ [render] ; $($t)+ } };
+}
+
+include_sql!("/tests/init.sql");
+include_sql!("/tests/render.sql");
+
+#[test]
+fn render() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.create_test_table()?;
+    db.insert_test_quotes()?;
+
+    let sql = db.render_get_quotes_by_ids(&[1, 5, 8], "C. S. Lewis")?;
+    assert_eq!(sql, "SELECT id, quote\n  FROM quotes\n WHERE id IN (1, 5, 8)\n   AND author <> 'C. S. Lewis'\n   AND 'C. S. Lewis' IS NOT NULL");
+
+    // rendered SQL selects the same rows as the method
+    let mut expected = Vec::new();
+    db.get_quotes_by_ids(&[1, 5, 8], "C. S. Lewis", |row| { expected.push(row.get::<_, i32>(0)?); Ok(()) })?;
+    let mut rendered = Vec::new();
+    let mut stmt = db.prepare(&sql)?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        rendered.push(row.get::<_, i32>(0)?);
+    }
+    assert_eq!(rendered, expected);
+    assert_eq!(rendered, [1, 5]);
+
+    let sql = db.render_get_quotes_by_ids(&[], "O'Brien")?;
+    assert!(sql.contains("id IN (NULL)"));
+    assert!(sql.contains("author <> 'O''Brien'"));
+
+    assert_eq!(db.render_count_quotes()?, "SELECT Count(*) FROM quotes");
+
+    let sql = db.render_insert_values(11, 0.5, &[0xCA, 0xFE], None)?;
+    assert_eq!(sql, "INSERT INTO quotes (id, author, quote) VALUES (11, 0.5 || NULL, X'CAFE')");
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+-- name: get_quotes_by_ids ?
+-- param: ids: i32 - quote IDs
+-- param: author: &str - quote author
+SELECT id, quote
+  FROM quotes
+ WHERE id IN (:ids)
+   AND author <> :author
+   AND :author IS NOT NULL
+/
+
+-- name: count_quotes ?
+SELECT Count(*) FROM quotes
+/
+
+-- name: insert_values !
+-- param: id: i32 - row ID
+-- param: real: f64 - real value
+-- param: blob: &[u8] - blob value
+-- param: note: Option<&str> - optional note
+INSERT INTO quotes (id, author, quote) VALUES (:id, :real || :note, :blob)
+/
@@ -0,0 +1,55 @@
+use include_sqlite_sql::{include_sql, impl_sql, sql_row};
+use rusqlite::{Result, Connection, Error};
+
+include_sql!("/tests/init.sql");
+include_sql!("/tests/queries.sql");
+
+sql_row!{
+    struct Quote {
+        author: &str,
+        quote: String,
+    }
+}
+
+sql_row!{
+    struct Missing {
+        author: &str,
+        year: i32,
+    }
+}
+
+#[test]
+fn rows() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.create_test_table()?;
+    db.insert_test_quotes()?;
+
+    let mut quotes = Vec::new();
+    db.get_quotes_longer_than(67, Quote::map(|row| {
+        quotes.push((row.author()?.to_string(), row.quote()?));
+        Ok(())
+    }))?;
+    assert_eq!(quotes, [
+        ("Vincent Van Gogh".to_string(), "Great things are done by a series of small things brought together.".to_string()),
+        ("Albert Einstein".to_string(), "Life is like riding a bicycle. To keep your balance, you must keep moving.".to_string()),
+    ]);
+
+    let mut row_num = 0;
+    let res = db.get_quotes_longer_than(0, Missing::map(|_| {
+        row_num += 1;
+        Ok(())
+    }));
+    assert!(matches!(res, Err(Error::InvalidColumnName(name)) if name == "year"));
+    assert_eq!(row_num, 0);
+
+    // misspelled columns are not noticed when there are no rows
+    db.get_quotes_longer_than(1000, Missing::map(|_| Ok(())))?;
+
+    let stmt = db.prepare(<Connection as QueriesSql>::GET_QUOTES_LONGER_THAN_SQL)?;
+    Quote::check(&stmt)?;
+    let res = Missing::check(&stmt);
+    assert!(matches!(res, Err(Error::InvalidColumnName(name)) if name == "year"));
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+CREATE TABLE quotes (
+    id      INTEGER PRIMARY KEY,
+    author  TEXT,
+    quote   TEXT
+);
@@ -0,0 +1,65 @@
+use include_sqlite_sql::include_sql;
+use rusqlite::{Result, Connection};
+
+#[cfg(feature = "render")]
+macro_rules! impl_sql {
+    ($($t:tt)+) => { include_sqlite_sql::impl_sql_extra!{ [render] ; $($t)+ } };
+}
+
+#[cfg(not(feature = "render"))]
+macro_rules! impl_sql {
+    ($($t:tt)+) => { include_sqlite_sql::impl_sql_extra!{ [] ; $($t)+ } };
+}
+
+include_sql!("/tests/init.sql");
+include_sql!("/tests/sensitive.sql");
+
+#[test]
+fn sensitive() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.create_test_table()?;
+    db.insert_test_quotes()?;
+
+    // sensitive arguments are bound as is
+    let count = db.insert_author_token("API client", "s3cr3t")?;
+    assert_eq!(count, 1);
+
+    let mut authors = Vec::new();
+    db.get_author_by_token("s3cr3t", &[1, 2], |row| {
+        authors.push(row.get::<_, String>(0)?);
+        Ok(())
+    })?;
+    assert_eq!(authors, ["API client"]);
+
+    let mut ids = Vec::new();
+    db.get_ids_by_tokens(&["n/a", "s3cr3t"], |row| {
+        ids.push(row.get::<_, i32>(0)?);
+        Ok(())
+    })?;
+    assert_eq!(ids, [11]);
+
+    let queries = <Connection as SensitiveSql>::QUERIES;
+    let params : Vec<_> = queries.iter().flat_map(|query| query.params).map(|param| (param.name, param.sensitive)).collect();
+    assert_eq!(params, [("author", false), ("token", true), ("token", true), ("ids", false), ("tokens", true)]);
+
+    Ok(())
+}
+
+#[cfg(feature = "render")]
+#[test]
+fn rendered_sql() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    let sql = db.render_insert_author_token("API client", "s3cr3t")?;
+    assert_eq!(sql, "INSERT INTO quotes (author, quote) VALUES ('API client', '***')");
+
+    let sql = db.render_get_author_by_token("s3cr3t", &[1, 2])?;
+    assert_eq!(sql, "SELECT author\n  FROM quotes\n WHERE quote = '***'\n   AND id NOT IN (1, 2)");
+    assert!(!sql.contains("s3cr3t"));
+
+    let sql = db.render_get_ids_by_tokens(&["n/a", "s3cr3t"])?;
+    assert_eq!(sql, "SELECT id\n  FROM quotes\n WHERE quote IN ('***', '***')");
+
+    Ok(())
+}
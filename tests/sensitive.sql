@@ -0,0 +1,21 @@
+-- name: insert_author_token !
+-- param: author: &str - author name
+-- param: token: sensitive<&str> - API token
+INSERT INTO quotes (author, quote) VALUES (:author, :token)
+/
+
+-- name: get_author_by_token ?
+-- param: token: sensitive<&str> - API token
+-- param: ids: i32 - quote IDs
+SELECT author
+  FROM quotes
+ WHERE quote = :token
+   AND id NOT IN (:ids)
+/
+
+-- name: get_ids_by_tokens ?
+-- param: tokens: sensitive<&str> - API tokens
+SELECT id
+  FROM quotes
+ WHERE quote IN (:tokens)
+/
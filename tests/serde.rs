@@ -0,0 +1,73 @@
+use include_sqlite_sql::include_sql;
+use rusqlite::{Result, Connection};
+use serde::Serialize;
+
+macro_rules! impl_sql {
+    ($($t:tt)+) => { include_sqlite_sql::impl_sql_extra!{ [with] ; $($t)+ } };
+}
+
+include_sql!("/tests/init.sql");
+include_sql!("/tests/serde.sql");
+
+#[derive(Serialize)]
+struct NewQuote<'a> {
+    author: &'a str,
+    quote: &'a str,
+}
+
+#[derive(Serialize)]
+struct QuoteFilter<'a> {
+    ids: &'a [i32],
+    author: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct Tags<'a> {
+    id: i32,
+    tags: &'a [&'a str],
+}
+
+#[test]
+fn serde() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.create_test_table()?;
+    db.insert_test_quotes()?;
+
+    let id = db.insert_new_quote_with(&NewQuote { author: "Benjamin Franklin", quote: "Well done is better than well said." }, |row| row.get::<_,i32>(0))?;
+
+    let mut quotes = Vec::new();
+    db.get_quote_by_id_with(&serde_json::json!({ "id": id }), |row| {
+        quotes.push(row.get::<_,String>(1)?);
+        Ok(())
+    })?;
+    assert_eq!(quotes, ["Well done is better than well said."]);
+
+    let mut authors = Vec::new();
+    db.get_quotes_by_ids_with(&QuoteFilter { ids: &[1, 2, id], author: None }, |row| {
+        authors.push(row.get::<_,String>(0)?);
+        Ok(())
+    })?;
+    assert_eq!(authors, ["John Keats", "Thomas Carlyle", "Benjamin Franklin"]);
+
+    let mut authors = Vec::new();
+    db.get_quotes_by_ids_with(&QuoteFilter { ids: &[1, 2, id], author: Some("Thomas Carlyle") }, |row| {
+        authors.push(row.get::<_,String>(0)?);
+        Ok(())
+    })?;
+    assert_eq!(authors, ["Thomas Carlyle"]);
+
+    let count = db.update_author_with(&Tags { id, tags: &["founding father", "inventor"] })?;
+    assert_eq!(count, 1);
+
+    db.get_quote_by_id(id, |row| {
+        let author : &str = row.get_ref(0)?.as_str()?;
+        assert_eq!(author, r#"["founding father","inventor"]"#);
+        Ok(())
+    })?;
+
+    let res = db.get_quote_by_id_with(&serde_json::json!({ "quote_id": id }), |_| Ok(()));
+    assert!(matches!(res, Err(rusqlite::Error::ToSqlConversionFailure(_))));
+
+    Ok(())
+}
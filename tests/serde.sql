@@ -0,0 +1,29 @@
+-- name: insert_new_quote ->
+-- param: author: &str
+-- param: quote: &str
+INSERT INTO quotes (author, quote) VALUES (:author, :quote) RETURNING id
+/
+
+-- name: get_quote_by_id ?
+-- param: id: i32
+SELECT author, quote
+  FROM quotes
+ WHERE id = :id
+/
+
+-- name: get_quotes_by_ids ?
+-- param: ids: i32
+-- param: author: Option<&str>
+SELECT author, quote
+  FROM quotes
+ WHERE id IN (:ids)
+   AND (:author IS NULL OR author = :author)
+ ORDER BY id
+/
+
+-- name: update_author !
+-- Stores tags in place of the author to test nested values
+-- param: tags: &str
+-- param: id: i32
+UPDATE quotes SET author = :tags WHERE id = :id
+/
@@ -0,0 +1,48 @@
+use include_sqlite_sql::{include_sql, sql_tuple, ColumnCountMismatch};
+use rusqlite::{Result, Connection, Error};
+use rusqlite::types::Type;
+
+macro_rules! impl_sql {
+    ($($t:tt)+) => { include_sqlite_sql::impl_sql_extra!{ [tuples] ; $($t)+ } };
+}
+
+include_sql!("/tests/init.sql");
+include_sql!("/tests/queries.sql");
+include_sql!("/tests/dynamic.sql");
+
+fn column_count_mismatch(err: Error) -> (usize, ColumnCountMismatch) {
+    match err {
+        Error::FromSqlConversionFailure(idx, Type::Null, err) => (idx, *err.downcast::<ColumnCountMismatch>().expect("column count mismatch")),
+        _ => panic!("unexpected error: {:?}", err),
+    }
+}
+
+#[test]
+fn tuples() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.create_test_table()?;
+    db.insert_test_quotes()?;
+
+    let counts : Vec<(i64,)> = db.count_quotes_tuples(sql_tuple!(i64))?;
+    assert_eq!(counts, [(10,)]);
+
+    let quotes : Vec<(String, String)> = db.get_quotes_longer_than_tuples(67, sql_tuple!(String, String))?;
+    assert_eq!(quotes, [
+        ("Vincent Van Gogh".to_string(), "Great things are done by a series of small things brought together.".to_string()),
+        ("Albert Einstein".to_string(), "Life is like riding a bicycle. To keep your balance, you must keep moving.".to_string()),
+    ]);
+
+    let params : Vec<(i64, bool, i64)> = db.get_repeated_params_tuples(2, &[1, 2], sql_tuple!(i64, bool, i64))?;
+    assert_eq!(params, [(2, true, 3)]);
+
+    let (idx, err) = column_count_mismatch(db.get_quotes_longer_than_tuples(67, sql_tuple!(String, String, i64)).unwrap_err());
+    assert_eq!((idx, err.expected, err.actual), (2, 3, 2));
+    assert_eq!(err.to_string(), "statement returns 2 columns, but 3 were expected");
+
+    // the number of columns is checked even if the statement returns no rows
+    let (idx, err) = column_count_mismatch(db.get_quotes_longer_than_tuples(1000, sql_tuple!(String)).unwrap_err());
+    assert_eq!((idx, err.expected, err.actual), (1, 1, 2));
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use include_sqlite_sql::{include_sql, impl_sql};
+use rusqlite::{Result, Connection};
+use chrono::{NaiveDate, NaiveDateTime};
+use time::OffsetDateTime;
+use uuid::Uuid;
+use serde_json::{json, Value};
+
+include_sql!("/tests/types.sql");
+
+#[test]
+fn types() -> Result<()> {
+    let db = Connection::open_in_memory()?;
+
+    db.create_loans_table()?;
+
+    let loan_id = Uuid::from_u128(0x6d5f_1a9e_8b2c_4e07_9f3d_2c41_7a60_b5e8);
+    let loaned_on = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap().and_hms_opt(10, 30, 0).unwrap();
+    let due_on = OffsetDateTime::from_unix_timestamp(1_711_929_600).unwrap();
+    let details = json!({ "patron": "Sheldon Cooper", "renewals": 0 });
+
+    let count = db.insert_loan(loan_id, "War and Peace", loaned_on, due_on, &details)?;
+    assert_eq!(count, 1);
+
+    let mut row_num = 0;
+    db.get_loans_since(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(), |row| {
+        row_num += 1;
+        let id : Uuid = row.get("loan_id")?;
+        let title : String = row.get("book_title")?;
+        let loan_ts : NaiveDateTime = row.get("loaned_on")?;
+        let due_ts : OffsetDateTime = row.get("due_on")?;
+        let loan_details : Value = row.get("details")?;
+        assert_eq!(id, loan_id);
+        assert_eq!(title, "War and Peace");
+        assert_eq!(loan_ts, loaned_on);
+        assert_eq!(due_ts, due_on);
+        assert_eq!(loan_details, details);
+        Ok(())
+    })?;
+    assert_eq!(row_num, 1);
+
+    Ok(())
+}
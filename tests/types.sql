@@ -0,0 +1,26 @@
+-- name: create_loans_table !
+CREATE TABLE loans (
+    loan_id     BLOB PRIMARY KEY,
+    book_title  TEXT,
+    loaned_on   DATETIME,
+    due_on      DATETIME,
+    details     TEXT
+)
+/
+
+-- name: insert_loan !
+-- param: loan_id: Uuid - loan ID
+-- param: book_title: &str - book title
+-- param: loaned_on: NaiveDateTime - loan timestamp
+-- param: due_on: OffsetDateTime - due date
+-- param: details: &Value - loan details
+INSERT INTO loans (loan_id, book_title, loaned_on, due_on, details)
+VALUES (:loan_id, :book_title, :loaned_on, :due_on, :details)
+/
+
+-- name: get_loans_since ?
+-- param: loaned_on: chrono::NaiveDateTime - earliest loan timestamp
+SELECT loan_id, book_title, loaned_on, due_on, details
+  FROM loans
+ WHERE loaned_on >= :loaned_on
+/